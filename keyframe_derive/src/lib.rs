@@ -2,49 +2,493 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 
-use quote::quote;
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+	ext::IdentExt,
+	parse::{Parse, ParseStream},
+	parse_macro_input, parse_quote,
+	punctuated::Punctuated,
+	Data, DataEnum, DataStruct, DataUnion, DeriveInput, Field, Fields, Ident, LitFloat, LitStr, Path, Token,
+	WherePredicate,
+};
 
+// Point in time at which fields marked with #[tween(discrete)] switch by default
+const DISCRETE_THRESHOLD: f64 = 0.5;
+// Fields marked with #[tween(skip)] keep the value of `from` until the end by default
+const SKIP_THRESHOLD: f64 = 1.0;
+
+// A single argument inside #[tween(...)]
+enum TweenArg {
+	Skip(Ident),
+	Discrete(Ident),
+	With(Ident, Path),
+	Threshold(Ident, LitFloat),
+	Crate(Ident, LitStr),
+	Bound(Ident, LitStr),
+}
+
+impl Parse for TweenArg {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let name = input.call(Ident::parse_any)?;
+
+		match name.to_string().as_str() {
+			"skip" => Ok(TweenArg::Skip(name)),
+			"discrete" => Ok(TweenArg::Discrete(name)),
+			"with" => {
+				input.parse::<Token![=]>()?;
+				Ok(TweenArg::With(name, input.parse()?))
+			}
+			"threshold" => {
+				input.parse::<Token![=]>()?;
+				Ok(TweenArg::Threshold(name, input.parse()?))
+			}
+			"crate" => {
+				input.parse::<Token![=]>()?;
+				Ok(TweenArg::Crate(name, input.parse()?))
+			}
+			"bound" => {
+				input.parse::<Token![=]>()?;
+				Ok(TweenArg::Bound(name, input.parse()?))
+			}
+			_ => Err(syn::Error::new(
+				name.span(),
+				format!("unknown tween attribute `{}`", name),
+			)),
+		}
+	}
+}
+
+// How a field is tweened
+enum FieldMode {
+	Ease,
+	With(Path),
+	Switch(f64),
+}
+
+fn field_mode(field: &Field) -> syn::Result<FieldMode> {
+	let mut mode: Option<(Ident, FieldMode)> = None;
+	let mut threshold: Option<(Ident, f64)> = None;
+
+	for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("tween")) {
+		let args = attr.parse_args_with(Punctuated::<TweenArg, Token![,]>::parse_terminated)?;
+
+		for arg in args {
+			let (name, new_mode) = match arg {
+				TweenArg::Skip(name) => (name, FieldMode::Switch(SKIP_THRESHOLD)),
+				TweenArg::Discrete(name) => (name, FieldMode::Switch(DISCRETE_THRESHOLD)),
+				TweenArg::With(name, path) => (name, FieldMode::With(path)),
+				TweenArg::Threshold(name, value) => {
+					if threshold.is_some() {
+						return Err(syn::Error::new(name.span(), "duplicate `threshold` attribute"));
+					}
+					threshold = Some((name, value.base10_parse()?));
+					continue;
+				}
+				TweenArg::Crate(name, _) | TweenArg::Bound(name, _) => {
+					return Err(syn::Error::new(
+						name.span(),
+						format!("`{}` can only be used on structs and enums", name),
+					))
+				}
+			};
+
+			if let Some((previous, _)) = &mode {
+				return Err(syn::Error::new(
+					name.span(),
+					format!("`{}` can't be combined with `{}`", name, previous),
+				));
+			}
+			mode = Some((name, new_mode));
+		}
+	}
+
+	match (mode, threshold) {
+		(Some((_, FieldMode::Switch(_))), Some((_, threshold))) => Ok(FieldMode::Switch(threshold)),
+		(_, Some((name, _))) => Err(syn::Error::new(
+			name.span(),
+			"`threshold` can only be used together with `skip` or `discrete`",
+		)),
+		(Some((_, mode)), None) => Ok(mode),
+		(None, None) => Ok(FieldMode::Ease),
+	}
+}
+
+fn field_ease(krate: &Path, field: &Field, from: TokenStream2, to: TokenStream2) -> syn::Result<TokenStream2> {
+	Ok(match field_mode(field)? {
+		FieldMode::Ease => quote! { #krate::CanTween::ease(#from, #to, time) },
+		FieldMode::With(path) => quote! { #path(#from, #to, time) },
+		FieldMode::Switch(threshold) => quote! { #krate::__private::switch(#from, #to, time, #threshold) },
+	})
+}
+
+// Expression that tweens a field in place and evaluates to false if its type doesn't implement Clone,
+// `from`, `to` and `out` are references to the field
+fn field_ease_into(
+	krate: &Path,
+	field: &Field,
+	from: TokenStream2,
+	to: TokenStream2,
+	out: TokenStream2,
+) -> syn::Result<TokenStream2> {
+	let ty = &field.ty;
+	let target = quote! { (&#krate::__private::Field::<#ty>(::core::marker::PhantomData)) };
+
+	Ok(match field_mode(field)? {
+		FieldMode::Ease => quote! { #target.ease_into(#from, #to, time, #out) },
+		FieldMode::With(path) => quote! { #target.map_into(#from, #to, #out, |from, to| #path(from, to, time)) },
+		FieldMode::Switch(threshold) => quote! { #target.switch_into(#from, #to, time, #threshold, #out) },
+	})
+}
+
+// Attributes of the struct or enum itself
+struct ContainerAttrs {
+	// Path to the keyframe crate
+	krate: Path,
+	// Point in time at which an enum switches between different variants
+	threshold: f64,
+	// Replaces the inferred bounds of the generated impl
+	bound: Option<Punctuated<WherePredicate, Token![,]>>,
+}
+
+// Finds keyframe in the dependencies of the crate being compiled, even if it has been renamed in Cargo.toml
+fn keyframe_path() -> Path {
+	match crate_name("keyframe") {
+		Ok(FoundCrate::Name(name)) => {
+			let name = Ident::new(&name, Span::call_site());
+			parse_quote! { ::#name }
+		}
+		// Also used for the examples and tests of keyframe itself
+		Ok(FoundCrate::Itself) | Err(_) => parse_quote! { ::keyframe },
+	}
+}
+
+fn container_attrs(input: &DeriveInput) -> syn::Result<ContainerAttrs> {
+	let mut krate = None;
+	let mut threshold = None;
+	let mut bound = None;
+
+	for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("tween")) {
+		let args = attr.parse_args_with(Punctuated::<TweenArg, Token![,]>::parse_terminated)?;
+
+		for arg in args {
+			match arg {
+				TweenArg::Threshold(name, _) if !matches!(input.data, Data::Enum(_)) => {
+					return Err(syn::Error::new(
+						name.span(),
+						"`threshold` can only be used on enums or on fields together with `skip` or `discrete`",
+					))
+				}
+				TweenArg::Threshold(name, _) if threshold.is_some() => {
+					return Err(syn::Error::new(name.span(), "duplicate `threshold` attribute"))
+				}
+				TweenArg::Threshold(_, value) => threshold = Some(value.base10_parse()?),
+				TweenArg::Crate(name, _) if krate.is_some() => {
+					return Err(syn::Error::new(name.span(), "duplicate `crate` attribute"))
+				}
+				TweenArg::Crate(_, path) => krate = Some(path.parse()?),
+				TweenArg::Bound(name, _) if bound.is_some() => {
+					return Err(syn::Error::new(name.span(), "duplicate `bound` attribute"))
+				}
+				TweenArg::Bound(_, predicates) => {
+					bound = Some(predicates.parse_with(Punctuated::parse_terminated)?);
+				}
+				TweenArg::Skip(name) | TweenArg::Discrete(name) | TweenArg::With(name, _) => {
+					return Err(syn::Error::new(
+						name.span(),
+						format!("`{}` can only be used on fields", name),
+					))
+				}
+			}
+		}
+	}
+
+	Ok(ContainerAttrs {
+		krate: krate.unwrap_or_else(keyframe_path),
+		threshold: threshold.unwrap_or(DISCRETE_THRESHOLD),
+		bound,
+	})
+}
+
+// If the tokens contain one of the type parameters
+fn mentions_type_param(tokens: TokenStream2, params: &[&Ident]) -> bool {
+	tokens.into_iter().any(|token| match token {
+		TokenTree::Ident(ident) => params.contains(&&ident),
+		TokenTree::Group(group) => mentions_type_param(group.stream(), params),
+		_ => false,
+	})
+}
+
+// Requires each tweened field that depends on a type parameter to implement CanTween, e.g. `Vec<T>: CanTween`
+fn inferred_bounds(krate: &Path, input: &DeriveInput) -> syn::Result<Vec<WherePredicate>> {
+	let params = input
+		.generics
+		.type_params()
+		.map(|param| &param.ident)
+		.collect::<Vec<_>>();
+	let fields: Vec<&Field> = match &input.data {
+		Data::Struct(DataStruct { fields, .. }) => fields.iter().collect(),
+		Data::Enum(DataEnum { variants, .. }) => variants.iter().flat_map(|variant| &variant.fields).collect(),
+		Data::Union(_) => Vec::new(),
+	};
+
+	let mut bounds = Vec::new();
+	for field in fields {
+		let ty = &field.ty;
+		if let FieldMode::Ease = field_mode(field)? {
+			if mentions_type_param(ty.to_token_stream(), &params) {
+				bounds.push(parse_quote! { #ty: #krate::CanTween });
+			}
+		}
+	}
+
+	Ok(bounds)
+}
+
+// Name of the variable that a field is bound to when matching on an enum variant
+fn binding(prefix: &str, index: usize) -> Ident {
+	format_ident!("__{}_{}", prefix, index)
+}
+
+// Pattern that binds each field of a variant to a variable, e.g. `Self::Variant { a: __from_0, b: __from_1 }`
+fn pattern(path: &TokenStream2, fields: &Fields, prefix: &str) -> TokenStream2 {
+	let bindings = (0..fields.len()).map(|i| binding(prefix, i));
+
+	match fields {
+		Fields::Named(fields) => {
+			let field_name = fields.named.iter().map(|field| &field.ident);
+			quote! { #path { #(#field_name: #bindings),* } }
+		}
+		Fields::Unnamed(_) => quote! { #path(#(#bindings),*) },
+		Fields::Unit => quote! { #path },
+	}
+}
+
+// Expression that constructs a struct or variant by tweening each field
+fn construct(
+	krate: &Path,
+	path: &TokenStream2,
+	fields: &Fields,
+	from: impl Fn(usize, &Field) -> TokenStream2,
+	to: impl Fn(usize, &Field) -> TokenStream2,
+) -> syn::Result<TokenStream2> {
+	let field_value = fields
+		.iter()
+		.enumerate()
+		.map(|(i, field)| field_ease(krate, field, from(i, field), to(i, field)))
+		.collect::<syn::Result<Vec<_>>>()?;
+
+	Ok(match fields {
+		Fields::Named(fields) => {
+			let field_name = fields.named.iter().map(|field| &field.ident);
+			quote! { #path { #(#field_name: #field_value,)* } }
+		}
+		Fields::Unnamed(_) => quote! { #path(#(#field_value,)*) },
+		Fields::Unit => quote! { #path },
+	})
+}
+
+// Expression that tweens each field of a struct or variant in place and evaluates to false as soon as one of them can't be
+fn construct_into(
+	krate: &Path,
+	fields: &Fields,
+	from: impl Fn(usize, &Field) -> TokenStream2,
+	to: impl Fn(usize, &Field) -> TokenStream2,
+	out: impl Fn(usize, &Field) -> TokenStream2,
+) -> syn::Result<TokenStream2> {
+	let in_place = fields
+		.iter()
+		.enumerate()
+		.map(|(i, field)| field_ease_into(krate, field, from(i, field), to(i, field), out(i, field)))
+		.collect::<syn::Result<Vec<_>>>()?;
+
+	Ok(if in_place.is_empty() {
+		quote! { true }
+	} else {
+		quote! { #(#in_place)&&* }
+	})
+}
+
+/// Implements `CanTween` for a structure by tweening each of its fields.
+///
+/// Enums tween the fields of the variant if both values have the same variant.
+/// Values with different variants switch from `from` to `to` half-way through, a different point in time (0.0 to 1.0) can be set with `#[tween(threshold = 0.25)]` on the enum.
+///
+/// Fields can be customized with the `tween` attribute:
+/// * `#[tween(skip)]` - The value of `from` is kept until the end, a different point in time (0.0 to 1.0) can be set with `threshold = 0.5`
+/// * `#[tween(discrete)]` - The value switches from `from` to `to` half-way through instead of being interpolated, a different point in time can be set with `threshold = 0.25`
+/// * `#[tween(with = path)]` - The function `path(from, to, time)` is used to interpolate the value
+///
+/// Generic parameters used by tweened fields are required to implement `CanTween`, e.g. `struct Path<T> { points: Vec<T> }` requires `Vec<T>: CanTween`.
+/// The inferred bounds can be replaced with `#[tween(bound = "T: CanTween + Clone")]` on the struct or enum.
+///
+/// `ease_into` tweens the fields in place if their types implement `Clone`, otherwise it clones and tweens the whole value.
+/// Fields that depend on a type parameter are only tweened in place if the bounds require them to implement `Clone`,
+/// e.g. with `#[tween(bound = "T: CanTween + Clone")]`.
+///
+/// The generated code refers to keyframe by the name it has in `Cargo.toml`.
+/// If keyframe is only available through a re-export the path can be set with `#[tween(crate = "my_engine::keyframe")]` on the struct or enum.
 // https://github.com/dtolnay/syn/issues/516
-#[proc_macro_derive(CanTween)]
+#[proc_macro_derive(CanTween, attributes(tween))]
 pub fn derive(input: TokenStream) -> TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
 
+	match expand(&input) {
+		Ok(tokens) => TokenStream::from(tokens),
+		Err(error) => TokenStream::from(error.to_compile_error()),
+	}
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
 	let struct_name = &input.ident;
-	let generics = &input.generics;
-	let where_clause = &input.generics.where_clause;
+	let ContainerAttrs {
+		krate,
+		threshold,
+		bound,
+	} = container_attrs(input)?;
 
-	match &input.data {
-		Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => {
-			let field_name = fields.named.iter().map(|field| &field.ident);
+	let mut generics = input.generics.clone();
+	let predicates = match bound {
+		Some(bound) => bound.into_iter().collect(),
+		None => inferred_bounds(&krate, input)?,
+	};
+	generics.make_where_clause().predicates.extend(predicates);
+	let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
-			TokenStream::from(quote! {
-				impl #generics keyframe::CanTween for #struct_name #generics #where_clause {
-					fn ease(mut from: Self, mut to: Self, time: impl keyframe::num_traits::Float) -> Self {
-						Self {
-							#(
-								#field_name: keyframe::CanTween::ease(from.#field_name, to.#field_name, time),
-							)*
-						}
-					}
+	let body = match &input.data {
+		Data::Struct(DataStruct { fields, .. }) => construct(
+			&krate,
+			&quote! { Self },
+			fields,
+			|i, field| member(i, field, quote! { from }),
+			|i, field| member(i, field, quote! { to }),
+		)?,
+		Data::Enum(DataEnum { variants, .. }) if variants.is_empty() => quote! { match from {} },
+		Data::Enum(DataEnum { variants, .. }) => {
+			let arms = variants
+				.iter()
+				.map(|variant| {
+					let variant_name = &variant.ident;
+					let path = quote! { Self::#variant_name };
+
+					let from_pattern = pattern(&path, &variant.fields, "from");
+					let to_pattern = pattern(&path, &variant.fields, "to");
+					let value = construct(
+						&krate,
+						&path,
+						&variant.fields,
+						|i, _| binding("from", i).into_token_stream(),
+						|i, _| binding("to", i).into_token_stream(),
+					)?;
+
+					Ok(quote! { (#from_pattern, #to_pattern) => #value, })
+				})
+				.collect::<syn::Result<Vec<_>>>()?;
+
+			quote! {
+				#[allow(unreachable_patterns)]
+				match (from, to) {
+					#(#arms)*
+					(from, to) => #krate::__private::switch(from, to, time, #threshold),
 				}
-			})
+			}
 		}
-		Data::Struct(DataStruct { fields: Fields::Unnamed(fields), .. }) => {
-			let field_idx = fields.unnamed.iter().enumerate().map(|(i, _)| syn::Index::from(i));
+		Data::Union(DataUnion { union_token, .. }) => {
+			return Err(syn::Error::new(
+				union_token.span,
+				"CanTween can only be derived for structs and enums",
+			))
+		}
+	};
 
-			TokenStream::from(quote! {
-				impl #generics keyframe::CanTween for #struct_name #generics #where_clause {
-					fn ease(mut from: Self, mut to: Self, time: impl keyframe::num_traits::Float) -> Self {
-						Self(
-							#(
-								keyframe::CanTween::ease(from.#field_idx, to.#field_idx, time),
-							)*
-						)
-					}
+	let in_place = match &input.data {
+		Data::Struct(DataStruct { fields, .. }) => construct_into(
+			&krate,
+			fields,
+			|i, field| {
+				let member = member(i, field, quote! { from });
+				quote! { &#member }
+			},
+			|i, field| {
+				let member = member(i, field, quote! { to });
+				quote! { &#member }
+			},
+			|i, field| {
+				let member = member(i, field, quote! { out });
+				quote! { &mut #member }
+			},
+		)?,
+		Data::Enum(DataEnum { variants, .. }) => {
+			let arms = variants
+				.iter()
+				.map(|variant| {
+					let variant_name = &variant.ident;
+					let path = quote! { Self::#variant_name };
+
+					let from_pattern = pattern(&path, &variant.fields, "from");
+					let to_pattern = pattern(&path, &variant.fields, "to");
+					let out_pattern = pattern(&path, &variant.fields, "out");
+					let in_place = construct_into(
+						&krate,
+						&variant.fields,
+						|i, _| binding("from", i).into_token_stream(),
+						|i, _| binding("to", i).into_token_stream(),
+						|i, _| binding("out", i).into_token_stream(),
+					)?;
+
+					Ok(quote! { (#from_pattern, #to_pattern, #out_pattern) => #in_place, })
+				})
+				.collect::<syn::Result<Vec<_>>>()?;
+
+			// Only values that all have the same variant are tweened in place
+			quote! {
+				match (from, to, &mut *out) {
+					#(#arms)*
+					_ => false,
 				}
-			})
+			}
+		}
+		Data::Union(_) => unreachable!(),
+	};
+
+	let body_into = match &input.data {
+		Data::Enum(DataEnum { variants, .. }) if variants.is_empty() => quote! { match *from {} },
+		_ => quote! {
+			use #krate::__private::{Fallback as _, InPlace as _};
+
+			if !(#in_place) {
+				*out = #krate::CanTween::ease(::core::clone::Clone::clone(from), ::core::clone::Clone::clone(to), time);
+			}
 		},
-		_ => panic!("Expected struct with fields!")
+	};
+
+	Ok(quote! {
+		impl #impl_generics #krate::CanTween for #struct_name #type_generics #where_clause {
+			fn ease(from: Self, to: Self, time: impl #krate::num_traits::Float) -> Self {
+				#body
+			}
+
+			// Fields whose type doesn't implement Clone fall back to tweening clones of `from` and `to`.
+			// The bound is higher-ranked so that it isn't rejected as trivially false for types that don't implement Clone.
+			#[allow(unreachable_patterns)]
+			fn ease_into(from: &Self, to: &Self, time: impl #krate::num_traits::Float, out: &mut Self)
+			where
+				for<'__keyframe> Self: ::core::clone::Clone,
+			{
+				#body_into
+			}
+		}
+	})
+}
+
+// Expression that accesses a field of a struct, e.g. `from.a` or `from.0`
+fn member(index: usize, field: &Field, value: TokenStream2) -> TokenStream2 {
+	match &field.ident {
+		Some(name) => quote! { #value.#name },
+		None => {
+			let index = syn::Index::from(index);
+			quote! { #value.#index }
+		}
 	}
-}
\ No newline at end of file
+}
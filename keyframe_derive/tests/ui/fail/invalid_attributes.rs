@@ -0,0 +1,33 @@
+use keyframe_derive::CanTween;
+
+#[derive(CanTween)]
+struct Unknown {
+	#[tween(interpolate)]
+	a: f32,
+}
+
+#[derive(CanTween)]
+struct Conflicting {
+	#[tween(skip, discrete)]
+	a: f32,
+}
+
+#[derive(CanTween)]
+struct ThresholdWithoutSwitch {
+	#[tween(threshold = 0.5)]
+	a: f32,
+}
+
+#[derive(CanTween)]
+#[tween(skip)]
+struct SkipOnStruct {
+	a: f32,
+}
+
+#[derive(CanTween)]
+struct CrateOnField {
+	#[tween(crate = "kf")]
+	a: f32,
+}
+
+fn main() {}
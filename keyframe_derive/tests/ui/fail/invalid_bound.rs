@@ -0,0 +1,10 @@
+use keyframe_derive::CanTween;
+
+#[derive(CanTween)]
+#[tween(bound = "T CanTween")]
+struct Pair<T> {
+	a: T,
+	b: T,
+}
+
+fn main() {}
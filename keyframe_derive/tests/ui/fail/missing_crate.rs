@@ -0,0 +1,7 @@
+use keyframe_derive::CanTween;
+
+#[derive(CanTween)]
+#[tween(crate = "engine::animation")]
+struct Position(f32, f32);
+
+fn main() {}
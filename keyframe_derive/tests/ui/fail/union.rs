@@ -0,0 +1,8 @@
+use keyframe_derive::CanTween;
+
+#[derive(CanTween)]
+union Value {
+	a: f32,
+}
+
+fn main() {}
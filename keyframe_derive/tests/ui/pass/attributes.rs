@@ -0,0 +1,47 @@
+use keyframe_derive::CanTween;
+use kf::{ease, functions::Linear, num_traits::Float};
+
+fn halfway<F: Float>(from: String, to: String, time: F) -> String {
+	if time < F::from(0.5).unwrap() {
+		from
+	} else {
+		to
+	}
+}
+
+#[derive(CanTween, Clone, Debug, PartialEq)]
+struct Sprite {
+	x: f32,
+	#[tween(skip)]
+	id: u32,
+	#[tween(discrete, threshold = 0.25)]
+	visible: bool,
+	#[tween(with = halfway)]
+	name: String,
+}
+
+fn main() {
+	let from = Sprite {
+		x: 0.0,
+		id: 1,
+		visible: false,
+		name: "from".into(),
+	};
+	let to = Sprite {
+		x: 1.0,
+		id: 2,
+		visible: true,
+		name: "to".into(),
+	};
+
+	assert_eq!(
+		ease(Linear, from.clone(), to.clone(), 0.3),
+		Sprite {
+			x: 0.3,
+			id: 1,
+			visible: true,
+			name: "from".into(),
+		}
+	);
+	assert_eq!(ease(Linear, from, to.clone(), 1.0), to);
+}
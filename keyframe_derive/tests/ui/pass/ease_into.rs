@@ -0,0 +1,124 @@
+use keyframe_derive::CanTween;
+use kf::{num_traits::Float, CanTween};
+
+fn halfway<F: Float>(from: String, to: String, time: F) -> String {
+	if time < F::from(0.5).unwrap() {
+		from
+	} else {
+		to
+	}
+}
+
+#[derive(CanTween, Clone, Debug, PartialEq)]
+#[tween(bound = "T: CanTween + Clone")]
+struct Mesh<T> {
+	vertices: Vec<[T; 2]>,
+	#[tween(skip)]
+	id: u32,
+	#[tween(with = halfway)]
+	name: String,
+}
+
+// `Vec<T>` isn't known to implement Clone, so the whole value is cloned and tweened
+#[derive(CanTween, Clone, Debug, PartialEq)]
+struct Path<T> {
+	points: Vec<T>,
+}
+
+#[derive(CanTween, Clone, Debug, PartialEq)]
+enum Shape {
+	Circle { radius: f32 },
+	Polygon(Vec<f32>),
+}
+
+#[derive(Debug, PartialEq)]
+struct Weight(f32);
+
+impl CanTween for Weight {
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Weight(f32::ease(from.0, to.0, time))
+	}
+}
+
+// Fields don't have to implement Clone as long as `ease_into` isn't used
+#[derive(CanTween, Debug, PartialEq)]
+struct Weights {
+	weights: [Weight; 2],
+}
+
+fn main() {
+	let from = Mesh {
+		vertices: vec![[0.0, 0.0], [1.0, 0.0]],
+		id: 1,
+		name: "from".into(),
+	};
+	let to = Mesh {
+		vertices: vec![[1.0, 2.0], [3.0, 2.0]],
+		id: 2,
+		name: "to".into(),
+	};
+
+	let mut out = from.clone();
+	let buffer = out.vertices.as_ptr();
+	CanTween::ease_into(&from, &to, 0.5, &mut out);
+	assert_eq!(
+		out,
+		Mesh {
+			vertices: vec![[0.5, 1.0], [2.0, 1.0]],
+			id: 1,
+			name: "to".into(),
+		}
+	);
+	// The vertices are tweened in place
+	assert_eq!(out.vertices.as_ptr(), buffer);
+
+	let mut out = Path { points: vec![] };
+	CanTween::ease_into(
+		&Path { points: vec![0.0, 1.0] },
+		&Path { points: vec![2.0, 3.0] },
+		0.5,
+		&mut out,
+	);
+	assert_eq!(out, Path { points: vec![1.0, 2.0] });
+
+	let mut out = Shape::Polygon(vec![]);
+	CanTween::ease_into(
+		&Shape::Polygon(vec![0.0, 1.0]),
+		&Shape::Polygon(vec![2.0, 3.0]),
+		0.5,
+		&mut out,
+	);
+	assert_eq!(out, Shape::Polygon(vec![1.0, 2.0]));
+
+	// `out` has a different variant than `from` and `to`
+	CanTween::ease_into(
+		&Shape::Circle { radius: 0.0 },
+		&Shape::Circle { radius: 2.0 },
+		0.5,
+		&mut out,
+	);
+	assert_eq!(out, Shape::Circle { radius: 1.0 });
+
+	CanTween::ease_into(
+		&Shape::Circle { radius: 0.0 },
+		&Shape::Polygon(vec![1.0]),
+		0.75,
+		&mut out,
+	);
+	assert_eq!(out, Shape::Polygon(vec![1.0]));
+
+	assert_eq!(
+		CanTween::ease(
+			Weights {
+				weights: [Weight(0.0), Weight(1.0)]
+			},
+			Weights {
+				weights: [Weight(1.0), Weight(3.0)]
+			},
+			0.5
+		),
+		Weights {
+			weights: [Weight(0.5), Weight(2.0)]
+		}
+	);
+}
@@ -0,0 +1,41 @@
+use keyframe_derive::CanTween;
+use kf::{ease, functions::Linear};
+
+#[derive(CanTween, Debug, PartialEq)]
+#[tween(threshold = 0.25)]
+enum Shape {
+	Circle { radius: f32 },
+	Rectangle(f32, f32),
+	Empty,
+}
+
+#[derive(CanTween, Debug, PartialEq)]
+struct Unit;
+
+#[derive(CanTween)]
+enum Never {}
+
+fn main() {
+	assert_eq!(
+		ease(
+			Linear,
+			Shape::Circle { radius: 0.0 },
+			Shape::Circle { radius: 2.0 },
+			0.5
+		),
+		Shape::Circle { radius: 1.0 }
+	);
+	assert_eq!(
+		ease(Linear, Shape::Rectangle(0.0, 0.0), Shape::Rectangle(1.0, 2.0), 0.5),
+		Shape::Rectangle(0.5, 1.0)
+	);
+	assert_eq!(
+		ease(Linear, Shape::Empty, Shape::Rectangle(1.0, 2.0), 0.2),
+		Shape::Empty
+	);
+	assert_eq!(
+		ease(Linear, Shape::Empty, Shape::Rectangle(1.0, 2.0), 0.3),
+		Shape::Rectangle(1.0, 2.0)
+	);
+	assert_eq!(ease(Linear, Unit, Unit, 0.5), Unit);
+}
@@ -0,0 +1,66 @@
+use keyframe_derive::CanTween;
+use kf::{ease, functions::Linear, CanTween};
+
+#[derive(CanTween, Debug, PartialEq)]
+struct Pair<T> {
+	a: T,
+	b: T,
+}
+
+// Only `Vec<T>: CanTween` is required, `Id` doesn't have to implement CanTween because it isn't tweened
+#[derive(CanTween, Debug, PartialEq)]
+struct Polygon<T: Clone, Id>
+where
+	Id: PartialEq,
+{
+	points: Vec<T>,
+	#[tween(skip)]
+	id: Id,
+}
+
+#[derive(CanTween, Debug, PartialEq)]
+enum Maybe<T> {
+	Just(T),
+	Nothing,
+}
+
+#[derive(CanTween, Debug, PartialEq)]
+#[tween(bound = "T: CanTween + Clone")]
+struct Explicit<T> {
+	points: Vec<T>,
+}
+
+fn main() {
+	assert_eq!(
+		ease(Linear, Pair { a: 0.0, b: 1.0 }, Pair { a: 1.0, b: 3.0 }, 0.5),
+		Pair { a: 0.5, b: 2.0 }
+	);
+	assert_eq!(
+		ease(
+			Linear,
+			Polygon {
+				points: vec![0.0, 0.0],
+				id: "a",
+			},
+			Polygon {
+				points: vec![1.0, 2.0],
+				id: "b",
+			},
+			0.5
+		),
+		Polygon {
+			points: vec![0.5, 1.0],
+			id: "a",
+		}
+	);
+	assert_eq!(ease(Linear, Maybe::Just(0.0), Maybe::Just(1.0), 0.5), Maybe::Just(0.5));
+	assert_eq!(
+		ease(
+			Linear,
+			Explicit { points: vec![0.0] },
+			Explicit { points: vec![1.0] },
+			0.5
+		),
+		Explicit { points: vec![0.5] }
+	);
+}
@@ -0,0 +1,17 @@
+mod engine {
+	pub use kf as animation;
+}
+
+use engine::animation::{ease, functions::Linear};
+use keyframe_derive::CanTween;
+
+#[derive(CanTween, Debug, PartialEq)]
+#[tween(crate = "engine::animation")]
+struct Position(f32, f32);
+
+fn main() {
+	assert_eq!(
+		ease(Linear, Position(0.0, 0.0), Position(1.0, 2.0), 0.5),
+		Position(0.5, 1.0)
+	);
+}
@@ -0,0 +1,16 @@
+// keyframe is a dev-dependency of keyframe_derive under the name `kf`
+use keyframe_derive::CanTween;
+use kf::{ease, functions::Linear};
+
+#[derive(CanTween, Debug, PartialEq)]
+struct Position {
+	x: f32,
+	y: f64,
+}
+
+fn main() {
+	assert_eq!(
+		ease(Linear, Position { x: 0.0, y: 0.0 }, Position { x: 1.0, y: 2.0 }, 0.5),
+		Position { x: 0.5, y: 1.0 }
+	);
+}
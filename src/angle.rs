@@ -0,0 +1,175 @@
+use core::marker::PhantomData;
+
+use crate::{as_f64, as_t, CanTween, Float};
+
+/// Direction in which an angle travels when it's tweened
+///
+/// # Note
+///
+/// Angles increase counterclockwise like in mathematics.
+/// If the Y axis points down (as in most screen coordinates) clockwise and counterclockwise are swapped visually.
+pub trait ArcDirection {
+	/// For the counterclockwise distance `delta` between two angles (in a range of `0.0..full_turn`),
+	/// calculate the signed distance that should be travelled.
+	fn distance(delta: f64, full_turn: f64) -> f64;
+}
+
+/// Takes the shortest arc between two angles, e.g. from 350° to 10° through 0°
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shortest;
+impl ArcDirection for Shortest {
+	#[inline]
+	fn distance(delta: f64, full_turn: f64) -> f64 {
+		if delta > full_turn / 2.0 {
+			delta - full_turn
+		} else {
+			delta
+		}
+	}
+}
+
+/// Takes the longest arc between two angles, e.g. from 350° to 10° through 180°.
+/// Tweening between two equal angles makes a full turn.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Longest;
+impl ArcDirection for Longest {
+	#[inline]
+	fn distance(delta: f64, full_turn: f64) -> f64 {
+		if delta > full_turn / 2.0 {
+			delta
+		} else {
+			delta - full_turn
+		}
+	}
+}
+
+/// Always travels clockwise (decreasing angle), e.g. from 10° to 350° through 0°
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Clockwise;
+impl ArcDirection for Clockwise {
+	#[inline]
+	fn distance(delta: f64, full_turn: f64) -> f64 {
+		if delta > 0.0 {
+			delta - full_turn
+		} else {
+			delta
+		}
+	}
+}
+
+/// Always travels counterclockwise (increasing angle), e.g. from 350° to 10° through 0°
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CounterClockwise;
+impl ArcDirection for CounterClockwise {
+	#[inline]
+	fn distance(delta: f64, _full_turn: f64) -> f64 {
+		delta
+	}
+}
+
+pub(crate) fn ease_angle<D: ArcDirection>(from: f64, to: f64, time: f64, full_turn: f64) -> f64 {
+	let mut delta = (to - from) % full_turn;
+	if delta < 0.0 {
+		delta += full_turn;
+	}
+
+	from + D::distance(delta, full_turn) * time
+}
+
+/// Angle in radians that is tweened along the arc specified by `D`
+///
+/// # Note
+///
+/// The tweened value is not wrapped, so tweening from `6.0` to `0.5` returns values larger than `2π` on the way.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Radians<T, D = Shortest> {
+	/// The angle in radians
+	pub value: T,
+	direction: PhantomData<D>,
+}
+
+impl<T, D> Radians<T, D> {
+	/// Creates a new angle in radians
+	#[inline]
+	pub fn new(value: T) -> Self {
+		Radians {
+			value,
+			direction: PhantomData,
+		}
+	}
+}
+
+impl<T, D> From<T> for Radians<T, D> {
+	#[inline]
+	fn from(value: T) -> Self {
+		Radians::new(value)
+	}
+}
+
+impl<T: Float, D> From<Degrees<T, D>> for Radians<T, D> {
+	#[inline]
+	fn from(degrees: Degrees<T, D>) -> Self {
+		Radians::new(degrees.value.to_radians())
+	}
+}
+
+impl<T: Float, D: ArcDirection> CanTween for Radians<T, D> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Radians::new(as_t(ease_angle::<D>(
+			as_f64(from.value),
+			as_f64(to.value),
+			as_f64(time),
+			core::f64::consts::PI * 2.0,
+		)))
+	}
+}
+
+/// Angle in degrees that is tweened along the arc specified by `D`
+///
+/// # Note
+///
+/// The tweened value is not wrapped, so tweening from `350.0` to `10.0` returns values larger than `360.0` on the way.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Degrees<T, D = Shortest> {
+	/// The angle in degrees
+	pub value: T,
+	direction: PhantomData<D>,
+}
+
+impl<T, D> Degrees<T, D> {
+	/// Creates a new angle in degrees
+	#[inline]
+	pub fn new(value: T) -> Self {
+		Degrees {
+			value,
+			direction: PhantomData,
+		}
+	}
+}
+
+impl<T, D> From<T> for Degrees<T, D> {
+	#[inline]
+	fn from(value: T) -> Self {
+		Degrees::new(value)
+	}
+}
+
+impl<T: Float, D> From<Radians<T, D>> for Degrees<T, D> {
+	#[inline]
+	fn from(radians: Radians<T, D>) -> Self {
+		Degrees::new(radians.value.to_degrees())
+	}
+}
+
+impl<T: Float, D: ArcDirection> CanTween for Degrees<T, D> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Degrees::new(as_t(ease_angle::<D>(
+			as_f64(from.value),
+			as_f64(to.value),
+			as_f64(time),
+			360.0,
+		)))
+	}
+}
@@ -0,0 +1,82 @@
+use cgmath::{
+	Decomposed, Matrix2, Matrix3, Matrix4, Point1, Point2, Point3, Quaternion, Vector1, Vector2, Vector3, Vector4,
+	VectorSpace,
+};
+
+use crate::{as_f64, as_t, rotation, transform, CanTween, Float};
+
+macro_rules! impl_can_tween_for_vector {
+	($($vector: ident { $($field: ident),+ }),+) => {
+		$(
+			impl<V: CanTween> CanTween for $vector<V> {
+				#[inline]
+				fn ease(from: Self, to: Self, time: impl Float) -> Self {
+					$vector {
+						$($field: V::ease(from.$field, to.$field, time),)+
+					}
+				}
+			}
+		)+
+	};
+}
+
+impl_can_tween_for_vector!(
+	Vector1 { x },
+	Vector2 { x, y },
+	Vector3 { x, y, z },
+	Vector4 { x, y, z, w },
+	Point1 { x },
+	Point2 { x, y },
+	Point3 { x, y, z }
+);
+
+impl<V: Float> CanTween for Quaternion<V> {
+	/// Spherical linear interpolation (slerp) between two rotations.
+	/// The shortest path is always taken and the returned quaternion is normalized.
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		let convert = |q: Self| [as_f64(q.v.x), as_f64(q.v.y), as_f64(q.v.z), as_f64(q.s)];
+		let [x, y, z, s] = rotation::slerp(convert(from), convert(to), as_f64(time));
+
+		Quaternion::new(as_t(s), as_t(x), as_t(y), as_t(z))
+	}
+}
+
+// Matrices are decomposed into translation, rotation, scale and skew which are interpolated separately,
+// see the implementations for mint matrices. 4x4 matrices are projective transforms.
+
+impl<V: Float> CanTween for Matrix2<V> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Self::from(transform::ease_linear_2d(from.into(), to.into(), time))
+	}
+}
+
+impl<V: Float> CanTween for Matrix3<V> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Self::from(transform::ease_linear_3d(from.into(), to.into(), time))
+	}
+}
+
+impl<V: Float> CanTween for Matrix4<V> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Self::from(transform::ease_projective_3d(from.into(), to.into(), time))
+	}
+}
+
+impl<V: VectorSpace + CanTween, R: CanTween> CanTween for Decomposed<V, R>
+where
+	V::Scalar: CanTween,
+{
+	/// Interpolates the scale, rotation and displacement separately
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Decomposed {
+			scale: V::Scalar::ease(from.scale, to.scale, time),
+			rot: R::ease(from.rot, to.rot, time),
+			disp: V::ease(from.disp, to.disp, time),
+		}
+	}
+}
@@ -0,0 +1,221 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::{as_f64, CanTween, Float};
+
+/// Policy for tweening between two lists of values that might have different lengths
+pub trait LengthPolicy {
+	/// Returns the interpolated list between `from` and `to` at the specified time
+	fn ease_vec<T: CanTween + Clone>(from: Vec<T>, to: Vec<T>, time: impl Float) -> Vec<T>;
+}
+
+// Lists can't be tweened element by element if one of them is empty, instead they switch half-way through
+#[inline]
+fn switch_if_empty<T>(from: Vec<T>, to: Vec<T>, time: impl Float) -> Result<Vec<T>, (Vec<T>, Vec<T>)> {
+	match (from.is_empty(), to.is_empty()) {
+		(false, false) => Err((from, to)),
+		_ if as_f64(time) < 0.5 => Ok(from),
+		_ => Ok(to),
+	}
+}
+
+#[inline]
+fn ease_same_length<T: CanTween>(from: Vec<T>, to: Vec<T>, time: impl Float) -> Vec<T> {
+	from.into_iter().zip(to).map(|(f, t)| T::ease(f, t, time)).collect()
+}
+
+#[inline]
+fn ease_slice_into<T: CanTween + Clone>(from: &[T], to: &[T], time: impl Float, out: &mut [T]) {
+	for ((f, t), o) in from.iter().zip(to).zip(out.iter_mut()) {
+		T::ease_into(f, t, time, o);
+	}
+}
+
+// Reuses the allocation of `out` and only clones the elements it is missing
+#[inline]
+fn ease_same_length_into<T: CanTween + Clone>(from: &[T], to: &[T], time: impl Float, out: &mut Vec<T>) {
+	out.truncate(from.len());
+	let length = out.len();
+	out.extend_from_slice(&from[length..]);
+	ease_slice_into(from, to, time, out);
+}
+
+/// The shorter list is padded by repeating its last element.
+/// If one of the lists is empty the lists switch half-way through.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PadWithLast;
+impl LengthPolicy for PadWithLast {
+	fn ease_vec<T: CanTween + Clone>(from: Vec<T>, to: Vec<T>, time: impl Float) -> Vec<T> {
+		let (mut from, mut to) = match switch_if_empty(from, to, time) {
+			Ok(result) => return result,
+			Err(lists) => lists,
+		};
+
+		let length = from.len().max(to.len());
+		for list in [&mut from, &mut to].iter_mut() {
+			let last = list[list.len() - 1].clone();
+			list.resize(length, last);
+		}
+
+		ease_same_length(from, to, time)
+	}
+}
+
+/// The shorter list is resampled to the length of the longer list by tweening between neighbouring elements,
+/// so that the elements of both lists are spread out evenly. Useful for morphing between polygons.
+/// If one of the lists is empty the lists switch half-way through.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Resample;
+impl Resample {
+	fn resample<T: CanTween + Clone>(list: Vec<T>, length: usize) -> Vec<T> {
+		if list.len() == length || list.len() < 2 || length < 2 {
+			let mut list = list;
+			let last = list[list.len() - 1].clone();
+			list.resize(length, last);
+			return list;
+		}
+
+		let step = (list.len() - 1) as f64 / (length - 1) as f64;
+		(0..length)
+			.map(|i| {
+				let position = i as f64 * step;
+				let index = (position as usize).min(list.len() - 2);
+				T::ease(list[index].clone(), list[index + 1].clone(), position - index as f64)
+			})
+			.collect()
+	}
+}
+impl LengthPolicy for Resample {
+	fn ease_vec<T: CanTween + Clone>(from: Vec<T>, to: Vec<T>, time: impl Float) -> Vec<T> {
+		let (from, to) = match switch_if_empty(from, to, time) {
+			Ok(result) => return result,
+			Err(lists) => lists,
+		};
+
+		let length = from.len().max(to.len());
+		ease_same_length(
+			Resample::resample(from, length),
+			Resample::resample(to, length),
+			time,
+		)
+	}
+}
+
+/// Only lists with the same length are tweened element by element.
+/// Lists with different lengths (including empty lists) are not tweened, they switch half-way through instead.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SameLength;
+impl LengthPolicy for SameLength {
+	fn ease_vec<T: CanTween + Clone>(from: Vec<T>, to: Vec<T>, time: impl Float) -> Vec<T> {
+		if from.len() == to.len() {
+			ease_same_length(from, to, time)
+		} else if as_f64(time) < 0.5 {
+			from
+		} else {
+			to
+		}
+	}
+}
+
+impl<T: CanTween + Clone> CanTween for Vec<T> {
+	/// Interpolates each element, lists with different lengths are handled with [`PadWithLast`].
+	/// Use [`Morph`] to choose how lists with different lengths are handled.
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		PadWithLast::ease_vec(from, to, time)
+	}
+
+	/// Interpolates each element in place if the lists have the same length
+	#[inline]
+	fn ease_into(from: &Self, to: &Self, time: impl Float, out: &mut Self) {
+		if from.len() == to.len() {
+			ease_same_length_into(from, to, time, out);
+		} else {
+			*out = Self::ease(from.clone(), to.clone(), time);
+		}
+	}
+}
+
+impl<T: CanTween + Clone> CanTween for Box<[T]> {
+	/// Interpolates each element, slices with different lengths are handled with [`PadWithLast`]
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		PadWithLast::ease_vec(from.into_vec(), to.into_vec(), time).into_boxed_slice()
+	}
+
+	/// Interpolates each element in place if all slices have the same length
+	#[inline]
+	fn ease_into(from: &Self, to: &Self, time: impl Float, out: &mut Self) {
+		if from.len() == to.len() && from.len() == out.len() {
+			ease_slice_into(from, to, time, out);
+		} else {
+			*out = Self::ease(from.clone(), to.clone(), time);
+		}
+	}
+}
+
+/// List of values that is tweened with the length policy `P`, e.g. the vertices of a polygon
+///
+/// ```rust
+/// use keyframe::{ease, functions::Linear, Morph, Resample, SameLength};
+///
+/// let triangle = Morph::<[f32; 2], Resample>::new(vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+/// let square = Morph::new(vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+///
+/// assert_eq!(ease(Linear, triangle, square, 0.5).values.len(), 4);
+///
+/// // With `SameLength` lists with different lengths switch half-way through instead
+/// let from = Morph::<f32, SameLength>::new(vec![0.0, 1.0]);
+/// let to = Morph::new(vec![2.0, 3.0, 4.0]);
+/// assert_eq!(ease(Linear, from.clone(), to.clone(), 0.25).values, [0.0, 1.0]);
+/// assert_eq!(ease(Linear, from, to, 0.75).values, [2.0, 3.0, 4.0]);
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Morph<T, P = PadWithLast> {
+	/// The values in this list
+	pub values: Vec<T>,
+	policy: PhantomData<P>,
+}
+
+impl<T, P> Morph<T, P> {
+	/// Creates a new list of values with a length policy
+	#[inline]
+	pub fn new(values: Vec<T>) -> Self {
+		Morph {
+			values,
+			policy: PhantomData,
+		}
+	}
+}
+
+// Implemented by hand because deriving it would require `P: Clone`
+impl<T: Clone, P> Clone for Morph<T, P> {
+	#[inline]
+	fn clone(&self) -> Self {
+		Morph::new(self.values.clone())
+	}
+}
+
+impl<T, P> From<Vec<T>> for Morph<T, P> {
+	#[inline]
+	fn from(values: Vec<T>) -> Self {
+		Morph::new(values)
+	}
+}
+
+impl<T: CanTween + Clone, P: LengthPolicy> CanTween for Morph<T, P> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Morph::new(P::ease_vec(from.values, to.values, time))
+	}
+
+	/// Interpolates each element in place if the lists have the same length
+	#[inline]
+	fn ease_into(from: &Self, to: &Self, time: impl Float, out: &mut Self) {
+		if from.values.len() == to.values.len() {
+			ease_same_length_into(&from.values, &to.values, time, &mut out.values);
+		} else {
+			*out = Self::ease(from.clone(), to.clone(), time);
+		}
+	}
+}
@@ -0,0 +1,459 @@
+//! Colors in sRGB, OKLab, OKLCH, HSL and HSV that are tweened in a perceptually sensible way.
+//!
+//! Tweening sRGB values directly gives dark and muddy midpoints, since sRGB values are not proportional to the amount of light.
+//! [`Srgba`] is tweened in linear light instead, [`Oklab`] and [`Oklch`] are tweened in a perceptually uniform space
+//! and the hue of [`Oklch`], [`Hsla`] and [`Hsva`] takes the shortest arc around the color wheel.
+//!
+//! All color types can be converted to and from [`Srgba`] with `From`/`Into`.
+//! Like in CSS, colors are tweened with premultiplied alpha so fading from a transparent color doesn't tint the result.
+//!
+//! ```rust
+//! use keyframe::{ease, functions::Linear, color::{Oklch, Srgba}};
+//!
+//! let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+//! let blue = Srgba::new(0.0, 0.0, 1.0, 1.0);
+//!
+//! let linear_light = ease(Linear, red, blue, 0.5);
+//! let perceptual: Srgba<f64> = ease(Linear, Oklch::from(red), Oklch::from(blue), 0.5).into();
+//! ```
+
+use crate::{angle::ease_angle, angle::Shortest, as_f64, as_t, CanTween, Float};
+
+// Below this chroma or saturation the hue of a color has no visible effect
+const POWERLESS_HUE_THRESHOLD: f64 = 1e-6;
+
+#[inline]
+fn lerp(from: f64, to: f64, time: f64) -> f64 {
+	from + (to - from) * time
+}
+
+/// Tweens three color components and alpha with premultiplied alpha.
+/// The component at `hue` (if any) is an angle in degrees and isn't premultiplied.
+fn mix(from: [f64; 4], to: [f64; 4], time: f64, hue: Option<usize>) -> [f64; 4] {
+	let alpha = lerp(from[3], to[3], time);
+	let mut result = [0.0, 0.0, 0.0, alpha];
+
+	for (i, value) in result.iter_mut().take(3).enumerate() {
+		*value = match i {
+			_ if Some(i) == hue => {
+				let hue = ease_angle::<Shortest>(from[i], to[i], time, 360.0) % 360.0;
+				if hue < 0.0 {
+					hue + 360.0
+				} else {
+					hue
+				}
+			}
+			_ if alpha == 0.0 => lerp(from[i], to[i], time),
+			_ => lerp(from[i] * from[3], to[i] * to[3], time) / alpha,
+		};
+	}
+
+	result
+}
+
+/// If the hue of one color has no effect (e.g. gray) it takes the hue of the other color so the hue doesn't spin
+fn fix_powerless_hue(from: &mut [f64; 4], to: &mut [f64; 4], hue: usize, strength: usize) {
+	if from[strength].abs() < POWERLESS_HUE_THRESHOLD {
+		from[hue] = to[hue];
+	} else if to[strength].abs() < POWERLESS_HUE_THRESHOLD {
+		to[hue] = from[hue];
+	}
+}
+
+fn to_linear(c: f64) -> f64 {
+	match c.abs() {
+		abs if abs <= 0.04045 => c / 12.92,
+		abs => ((abs + 0.055) / 1.055).powf(2.4).copysign(c),
+	}
+}
+
+fn to_gamma(c: f64) -> f64 {
+	match c.abs() {
+		abs if abs <= 0.0031308 => c * 12.92,
+		abs => (1.055 * abs.powf(1.0 / 2.4) - 0.055).copysign(c),
+	}
+}
+
+// https://bottosson.github.io/posts/oklab/
+fn linear_to_oklab([r, g, b, alpha]: [f64; 4]) -> [f64; 4] {
+	let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+	let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+	let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+	[
+		0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+		1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+		0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+		alpha,
+	]
+}
+
+fn oklab_to_linear([l, a, b, alpha]: [f64; 4]) -> [f64; 4] {
+	let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+	let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+	let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+	let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+	[
+		4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+		-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+		-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+		alpha,
+	]
+}
+
+fn oklab_to_oklch([l, a, b, alpha]: [f64; 4]) -> [f64; 4] {
+	let hue = b.atan2(a).to_degrees();
+	[l, a.hypot(b), if hue < 0.0 { hue + 360.0 } else { hue }, alpha]
+}
+
+fn oklch_to_oklab([l, c, h, alpha]: [f64; 4]) -> [f64; 4] {
+	let (sin, cos) = h.to_radians().sin_cos();
+	[l, c * cos, c * sin, alpha]
+}
+
+/// Hue in degrees, the largest and the smallest component of an sRGB color
+fn hue_max_min([r, g, b, _]: [f64; 4]) -> (f64, f64, f64) {
+	let max = r.max(g).max(b);
+	let min = r.min(g).min(b);
+	let delta = max - min;
+
+	let hue = match max {
+		_ if delta == 0.0 => 0.0,
+		_ if max == r => 60.0 * ((g - b) / delta),
+		_ if max == g => 60.0 * ((b - r) / delta + 2.0),
+		_ => 60.0 * ((r - g) / delta + 4.0),
+	};
+
+	(if hue < 0.0 { hue + 360.0 } else { hue }, max, min)
+}
+
+/// sRGB color for a hue in degrees, chroma and the amount to add to all components
+fn hue_to_rgb(hue: f64, chroma: f64, m: f64, alpha: f64) -> [f64; 4] {
+	let h = (hue % 360.0 + 360.0) % 360.0 / 60.0;
+	let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+
+	let (r, g, b) = match h as u8 {
+		0 => (chroma, x, 0.0),
+		1 => (x, chroma, 0.0),
+		2 => (0.0, chroma, x),
+		3 => (0.0, x, chroma),
+		4 => (x, 0.0, chroma),
+		_ => (chroma, 0.0, x),
+	};
+
+	[r + m, g + m, b + m, alpha]
+}
+
+fn srgb_to_hsl(rgba: [f64; 4]) -> [f64; 4] {
+	let (hue, max, min) = hue_max_min(rgba);
+	let lightness = (max + min) / 2.0;
+	let divisor = 1.0 - (2.0 * lightness - 1.0).abs();
+	let saturation = if divisor == 0.0 { 0.0 } else { (max - min) / divisor };
+
+	[hue, saturation, lightness, rgba[3]]
+}
+
+fn hsl_to_srgb([h, s, l, alpha]: [f64; 4]) -> [f64; 4] {
+	let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+	hue_to_rgb(h, chroma, l - chroma / 2.0, alpha)
+}
+
+fn srgb_to_hsv(rgba: [f64; 4]) -> [f64; 4] {
+	let (hue, max, min) = hue_max_min(rgba);
+	[hue, if max == 0.0 { 0.0 } else { (max - min) / max }, max, rgba[3]]
+}
+
+fn hsv_to_srgb([h, s, v, alpha]: [f64; 4]) -> [f64; 4] {
+	let chroma = v * s;
+	hue_to_rgb(h, chroma, v - chroma, alpha)
+}
+
+/// Color in the sRGB color space with alpha, all components are in a range of 0.0 to 1.0.
+///
+/// The red, green and blue components are converted to linear light before they are tweened and converted back afterwards.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Srgba<T> {
+	/// Red
+	pub r: T,
+	/// Green
+	pub g: T,
+	/// Blue
+	pub b: T,
+	/// Alpha (opacity)
+	pub a: T,
+}
+
+impl<T: Float> Srgba<T> {
+	/// Creates a new color from red, green, blue and alpha in a range of 0.0 to 1.0
+	#[inline]
+	pub fn new(r: T, g: T, b: T, a: T) -> Self {
+		Srgba { r, g, b, a }
+	}
+
+	/// Creates a new color from 8-bit red, green, blue and alpha
+	#[inline]
+	pub fn from_rgba8([r, g, b, a]: [u8; 4]) -> Self {
+		let convert = |c: u8| as_t(c as f64 / 255.0);
+		Srgba::new(convert(r), convert(g), convert(b), convert(a))
+	}
+
+	/// Converts this color to 8-bit red, green, blue and alpha, clamping any components outside the range of 0.0 to 1.0
+	#[inline]
+	pub fn to_rgba8(self) -> [u8; 4] {
+		let convert = |c: T| (as_f64(c).clamp(0.0, 1.0) * 255.0).round() as u8;
+		[convert(self.r), convert(self.g), convert(self.b), convert(self.a)]
+	}
+
+	fn to_array(self) -> [f64; 4] {
+		[as_f64(self.r), as_f64(self.g), as_f64(self.b), as_f64(self.a)]
+	}
+
+	fn from_array([r, g, b, a]: [f64; 4]) -> Self {
+		Srgba::new(as_t(r), as_t(g), as_t(b), as_t(a))
+	}
+
+	fn to_linear(self) -> [f64; 4] {
+		let [r, g, b, a] = self.to_array();
+		[to_linear(r), to_linear(g), to_linear(b), a]
+	}
+
+	fn from_linear([r, g, b, a]: [f64; 4]) -> Self {
+		Srgba::from_array([to_gamma(r), to_gamma(g), to_gamma(b), a])
+	}
+}
+
+impl<T: Float> CanTween for Srgba<T> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Srgba::from_linear(mix(from.to_linear(), to.to_linear(), as_f64(time), None))
+	}
+}
+
+/// Color in the perceptually uniform [OKLab](https://bottosson.github.io/posts/oklab/) color space with alpha.
+///
+/// Tweening in OKLab keeps the perceived lightness and saturation of the midpoints close to the endpoints.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Oklab<T> {
+	/// Perceived lightness, 0.0 to 1.0
+	pub l: T,
+	/// Green (negative) to red (positive)
+	pub a: T,
+	/// Blue (negative) to yellow (positive)
+	pub b: T,
+	/// Alpha (opacity), 0.0 to 1.0
+	pub alpha: T,
+}
+
+impl<T: Float> Oklab<T> {
+	/// Creates a new color from lightness, a, b and alpha
+	#[inline]
+	pub fn new(l: T, a: T, b: T, alpha: T) -> Self {
+		Oklab { l, a, b, alpha }
+	}
+
+	fn to_array(self) -> [f64; 4] {
+		[as_f64(self.l), as_f64(self.a), as_f64(self.b), as_f64(self.alpha)]
+	}
+
+	fn from_array([l, a, b, alpha]: [f64; 4]) -> Self {
+		Oklab::new(as_t(l), as_t(a), as_t(b), as_t(alpha))
+	}
+}
+
+impl<T: Float> CanTween for Oklab<T> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Oklab::from_array(mix(from.to_array(), to.to_array(), as_f64(time), None))
+	}
+}
+
+impl<T: Float> From<Srgba<T>> for Oklab<T> {
+	#[inline]
+	fn from(color: Srgba<T>) -> Self {
+		Oklab::from_array(linear_to_oklab(color.to_linear()))
+	}
+}
+
+impl<T: Float> From<Oklab<T>> for Srgba<T> {
+	#[inline]
+	fn from(color: Oklab<T>) -> Self {
+		Srgba::from_linear(oklab_to_linear(color.to_array()))
+	}
+}
+
+/// Color in the OKLCH color space (the polar form of [`Oklab`]) with alpha.
+///
+/// The hue takes the shortest arc around the color wheel when tweened.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Oklch<T> {
+	/// Perceived lightness, 0.0 to 1.0
+	pub l: T,
+	/// Chroma (colorfulness), 0.0 to about 0.4
+	pub c: T,
+	/// Hue in degrees
+	pub h: T,
+	/// Alpha (opacity), 0.0 to 1.0
+	pub alpha: T,
+}
+
+impl<T: Float> Oklch<T> {
+	/// Creates a new color from lightness, chroma, hue in degrees and alpha
+	#[inline]
+	pub fn new(l: T, c: T, h: T, alpha: T) -> Self {
+		Oklch { l, c, h, alpha }
+	}
+
+	fn to_array(self) -> [f64; 4] {
+		[as_f64(self.l), as_f64(self.c), as_f64(self.h), as_f64(self.alpha)]
+	}
+
+	fn from_array([l, c, h, alpha]: [f64; 4]) -> Self {
+		Oklch::new(as_t(l), as_t(c), as_t(h), as_t(alpha))
+	}
+}
+
+impl<T: Float> CanTween for Oklch<T> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		let (mut from, mut to) = (from.to_array(), to.to_array());
+		fix_powerless_hue(&mut from, &mut to, 2, 1);
+		Oklch::from_array(mix(from, to, as_f64(time), Some(2)))
+	}
+}
+
+impl<T: Float> From<Oklab<T>> for Oklch<T> {
+	#[inline]
+	fn from(color: Oklab<T>) -> Self {
+		Oklch::from_array(oklab_to_oklch(color.to_array()))
+	}
+}
+
+impl<T: Float> From<Oklch<T>> for Oklab<T> {
+	#[inline]
+	fn from(color: Oklch<T>) -> Self {
+		Oklab::from_array(oklch_to_oklab(color.to_array()))
+	}
+}
+
+impl<T: Float> From<Srgba<T>> for Oklch<T> {
+	#[inline]
+	fn from(color: Srgba<T>) -> Self {
+		Oklch::from_array(oklab_to_oklch(linear_to_oklab(color.to_linear())))
+	}
+}
+
+impl<T: Float> From<Oklch<T>> for Srgba<T> {
+	#[inline]
+	fn from(color: Oklch<T>) -> Self {
+		Srgba::from_linear(oklab_to_linear(oklch_to_oklab(color.to_array())))
+	}
+}
+
+/// Color in the HSL (hue, saturation, lightness) color space with alpha.
+///
+/// The hue takes the shortest arc around the color wheel when tweened.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Hsla<T> {
+	/// Hue in degrees
+	pub h: T,
+	/// Saturation, 0.0 to 1.0
+	pub s: T,
+	/// Lightness, 0.0 to 1.0
+	pub l: T,
+	/// Alpha (opacity), 0.0 to 1.0
+	pub a: T,
+}
+
+impl<T: Float> Hsla<T> {
+	/// Creates a new color from hue in degrees, saturation, lightness and alpha
+	#[inline]
+	pub fn new(h: T, s: T, l: T, a: T) -> Self {
+		Hsla { h, s, l, a }
+	}
+
+	fn to_array(self) -> [f64; 4] {
+		[as_f64(self.h), as_f64(self.s), as_f64(self.l), as_f64(self.a)]
+	}
+
+	fn from_array([h, s, l, a]: [f64; 4]) -> Self {
+		Hsla::new(as_t(h), as_t(s), as_t(l), as_t(a))
+	}
+}
+
+impl<T: Float> CanTween for Hsla<T> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		let (mut from, mut to) = (from.to_array(), to.to_array());
+		fix_powerless_hue(&mut from, &mut to, 0, 1);
+		Hsla::from_array(mix(from, to, as_f64(time), Some(0)))
+	}
+}
+
+impl<T: Float> From<Srgba<T>> for Hsla<T> {
+	#[inline]
+	fn from(color: Srgba<T>) -> Self {
+		Hsla::from_array(srgb_to_hsl(color.to_array()))
+	}
+}
+
+impl<T: Float> From<Hsla<T>> for Srgba<T> {
+	#[inline]
+	fn from(color: Hsla<T>) -> Self {
+		Srgba::from_array(hsl_to_srgb(color.to_array()))
+	}
+}
+
+/// Color in the HSV (hue, saturation, value) color space with alpha.
+///
+/// The hue takes the shortest arc around the color wheel when tweened.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Hsva<T> {
+	/// Hue in degrees
+	pub h: T,
+	/// Saturation, 0.0 to 1.0
+	pub s: T,
+	/// Value (brightness), 0.0 to 1.0
+	pub v: T,
+	/// Alpha (opacity), 0.0 to 1.0
+	pub a: T,
+}
+
+impl<T: Float> Hsva<T> {
+	/// Creates a new color from hue in degrees, saturation, value and alpha
+	#[inline]
+	pub fn new(h: T, s: T, v: T, a: T) -> Self {
+		Hsva { h, s, v, a }
+	}
+
+	fn to_array(self) -> [f64; 4] {
+		[as_f64(self.h), as_f64(self.s), as_f64(self.v), as_f64(self.a)]
+	}
+
+	fn from_array([h, s, v, a]: [f64; 4]) -> Self {
+		Hsva::new(as_t(h), as_t(s), as_t(v), as_t(a))
+	}
+}
+
+impl<T: Float> CanTween for Hsva<T> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		let (mut from, mut to) = (from.to_array(), to.to_array());
+		fix_powerless_hue(&mut from, &mut to, 0, 1);
+		Hsva::from_array(mix(from, to, as_f64(time), Some(0)))
+	}
+}
+
+impl<T: Float> From<Srgba<T>> for Hsva<T> {
+	#[inline]
+	fn from(color: Srgba<T>) -> Self {
+		Hsva::from_array(srgb_to_hsv(color.to_array()))
+	}
+}
+
+impl<T: Float> From<Hsva<T>> for Srgba<T> {
+	#[inline]
+	fn from(color: Hsva<T>) -> Self {
+		Srgba::from_array(hsv_to_srgb(color.to_array()))
+	}
+}
@@ -0,0 +1,228 @@
+use alloc::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+use core::fmt::Write;
+
+use super::{CssKeyframes, CssProperty};
+use crate::{
+	color::Srgba,
+	functions::{EasingDescriptor, StepPosition},
+	AnimationSequence, EasingFunction, Keyframe,
+};
+
+// Functions without a CSS equivalent are sampled at every percent
+const APPROXIMATION_SAMPLES: usize = 100;
+// Samples are left out if linear() is still this close to the function without them
+const APPROXIMATION_TOLERANCE: f64 = 0.001;
+
+// Bézier curves that have a keyword in CSS
+const KEYWORDS: [(&str, [f32; 4]); 4] = [
+	("ease", [0.25, 0.1, 0.25, 1.0]),
+	("ease-in", [0.42, 0.0, 1.0, 1.0]),
+	("ease-out", [0.0, 0.0, 0.58, 1.0]),
+	("ease-in-out", [0.42, 0.0, 0.58, 1.0]),
+];
+
+/// Formats a number for CSS with at most four decimals and without trailing zeros
+fn number(value: f64) -> String {
+	let mut text = format!("{:.4}", value);
+	if text.contains('.') {
+		text.truncate(text.trim_end_matches('0').trim_end_matches('.').len());
+	}
+	if text == "-0" {
+		text.remove(0);
+	}
+	text
+}
+
+fn color(color: Srgba<f64>) -> String {
+	match color.to_rgba8() {
+		[r, g, b, 255] => format!("#{:02x}{:02x}{:02x}", r, g, b),
+		[r, g, b, _] => format!("rgb({} {} {} / {})", r, g, b, number(color.a.clamp(0.0, 1.0))),
+	}
+}
+
+/// Writes an easing function as a CSS [`<easing-function>`](https://www.w3.org/TR/css-easing-2/#easing-functions).
+///
+/// [`Linear`](crate::functions::Linear), [`BezierCurve`](crate::functions::BezierCurve),
+/// [`Steps`](crate::functions::Steps), [`PiecewiseLinear`](crate::functions::PiecewiseLinear),
+/// [`Step`](crate::functions::Step) and [`Hold`](crate::functions::Hold) are written exactly.
+/// All other functions (including user-defined functions) are approximated with `linear()` stops sampled from the curve.
+///
+/// ```rust
+/// use keyframe::{css::write_timing_function, functions::*};
+///
+/// assert_eq!(write_timing_function(&BezierCurve::from([0.42, 0.0].into(), [1.0, 1.0].into())), "ease-in");
+/// assert_eq!(write_timing_function(&Steps::new(4, StepPosition::JumpStart)), "steps(4, jump-start)");
+/// assert!(write_timing_function(&EaseInOutCubic).starts_with("linear(0 0%, "));
+/// ```
+pub fn write_timing_function(function: &(impl EasingFunction + ?Sized)) -> String {
+	match function.descriptor() {
+		Some(EasingDescriptor::Linear) => "linear".to_string(),
+		Some(EasingDescriptor::Hold) => "steps(1)".to_string(),
+		Some(EasingDescriptor::Step) => "linear(0, 0 50%, 1 50%, 1)".to_string(),
+		Some(EasingDescriptor::BezierCurve(curve)) => {
+			let (p1, p2) = (curve.p1(), curve.p2());
+			let points = [p1.x, p1.y, p2.x, p2.y];
+
+			match KEYWORDS.iter().find(|(_, keyword)| *keyword == points) {
+				Some((name, _)) => name.to_string(),
+				None => {
+					let points: Vec<String> = points.iter().map(|&p| number(p as f64)).collect();
+					format!("cubic-bezier({})", points.join(", "))
+				}
+			}
+		}
+		Some(EasingDescriptor::Steps(steps)) => match steps.position() {
+			StepPosition::JumpEnd => format!("steps({})", steps.steps()),
+			StepPosition::JumpStart => format!("steps({}, jump-start)", steps.steps()),
+			StepPosition::JumpNone => format!("steps({}, jump-none)", steps.steps()),
+			StepPosition::JumpBoth => format!("steps({}, jump-both)", steps.steps()),
+		},
+		Some(EasingDescriptor::PiecewiseLinear(linear)) if linear.points().len() >= 2 => {
+			linear_function(linear.points().iter().copied())
+		}
+		_ => linear_function(approximate(function)),
+	}
+}
+
+fn linear_function(points: impl Iterator<Item = [f64; 2]>) -> String {
+	let stops: Vec<String> = points
+		.map(|[input, output]| format!("{} {}%", number(output), number(input * 100.0)))
+		.collect();
+	format!("linear({})", stops.join(", "))
+}
+
+/// Samples a function and leaves out samples that are close enough to the line between the samples around them
+fn approximate(function: &(impl EasingFunction + ?Sized)) -> impl Iterator<Item = [f64; 2]> {
+	let samples: Vec<[f64; 2]> = (0..=APPROXIMATION_SAMPLES)
+		.map(|i| {
+			let x = i as f64 / APPROXIMATION_SAMPLES as f64;
+			[x, function.y(x)]
+		})
+		.collect();
+
+	let mut points = Vec::new();
+	let mut start = 0;
+	points.push(samples[0]);
+
+	while start < samples.len() - 1 {
+		// Extend the line from the last point for as long as it stays close to every sample it skips
+		let mut end = start + 1;
+		while end + 1 < samples.len() {
+			let ([x1, y1], [x2, y2]) = (samples[start], samples[end + 1]);
+			let fits = samples[start + 1..=end]
+				.iter()
+				.all(|&[x, y]| (y1 + (y2 - y1) * (x - x1) / (x2 - x1) - y).abs() <= APPROXIMATION_TOLERANCE);
+
+			if !fits {
+				break;
+			}
+			end += 1;
+		}
+
+		points.push(samples[end]);
+		start = end;
+	}
+
+	points.into_iter()
+}
+
+/// Writes a `@keyframes` rule with the animations of all properties, the reverse of [`parse_keyframes`](super::parse_keyframes).
+///
+/// The times of the keyframes are converted to percentages of `duration`, keyframes before 0 or after `duration` are
+/// clamped to 0% and 100%.
+/// Keyframes of different properties that are at the same time and use the same easing function share a keyframe block.
+///
+/// Opaque colors are written as hex colors and other colors with `rgb()`. Keep in mind that browsers tween colors in sRGB,
+/// while [`Srgba`] is tweened in linear light, so the colors in between keyframes are slightly different.
+///
+/// ```rust
+/// use keyframe::{css::{write_keyframes, CssKeyframes, CssProperty}, functions::Linear, keyframes, AnimationSequence};
+///
+/// let fade = CssKeyframes {
+///     name: "fade".into(),
+///     properties: vec![("opacity".into(), CssProperty::Number(keyframes![(0.0, 0.0, Linear), (1.0, 2.0)]))],
+/// };
+///
+/// assert_eq!(
+///     write_keyframes(&fade, 2.0),
+///     "@keyframes fade {\n  0% { opacity: 0; animation-timing-function: linear; }\n  100% { opacity: 1; }\n}\n"
+/// );
+/// ```
+pub fn write_keyframes(keyframes: &CssKeyframes, duration: f64) -> String {
+	// (offset, timing function, declarations) where the timing function of the last keyframe of a property doesn't matter
+	let mut blocks: Vec<(f64, Option<String>, Vec<String>)> = Vec::new();
+
+	for (name, property) in &keyframes.properties {
+		let declarations: Vec<(f64, Option<String>, String)> = match property {
+			CssProperty::Number(sequence) => declarations(sequence, duration, |&value| number(value)),
+			CssProperty::Length(sequence, unit) => {
+				declarations(sequence, duration, |&value| format!("{}{}", number(value), unit))
+			}
+			CssProperty::Color(sequence) => declarations(sequence, duration, |&value| color(value)),
+		};
+
+		for (offset, function, value) in declarations {
+			let declaration = format!("{}: {};", name, value);
+			let block = blocks.iter_mut().find(|(other_offset, other_function, _)| {
+				*other_offset == offset
+					&& (function.is_none() || other_function.is_none() || *other_function == function)
+			});
+
+			match block {
+				Some(block) => {
+					if block.1.is_none() {
+						block.1 = function;
+					}
+					block.2.push(declaration);
+				}
+				None => blocks.push((offset, function, alloc::vec![declaration])),
+			}
+		}
+	}
+
+	blocks.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(core::cmp::Ordering::Equal));
+
+	let mut css = String::new();
+	let _ = writeln!(css, "@keyframes {} {{", keyframes.name);
+	for (offset, function, declarations) in blocks {
+		let _ = write!(css, "  {}% {{ {}", number(offset * 100.0), declarations.join(" "));
+		if let Some(function) = function {
+			let _ = write!(css, " animation-timing-function: {};", function);
+		}
+		css.push_str(" }\n");
+	}
+	css.push_str("}\n");
+	css
+}
+
+/// (offset, timing function, value) of every keyframe in a sequence, the last keyframe has no timing function
+fn declarations<T>(
+	sequence: &AnimationSequence<T>,
+	duration: f64,
+	value: impl Fn(&T) -> String,
+) -> Vec<(f64, Option<String>, String)> {
+	let keyframes = &sequence.sequence;
+
+	keyframes
+		.iter()
+		.enumerate()
+		.map(|(i, keyframe): (usize, &Keyframe<T>)| {
+			let offset = if duration > 0.0 {
+				(keyframe.time() / duration).clamp(0.0, 1.0)
+			} else {
+				0.0
+			};
+			let function = if i + 1 < keyframes.len() {
+				Some(write_timing_function(keyframe.function()))
+			} else {
+				None
+			};
+
+			(offset, function, value(&keyframe.value))
+		})
+		.collect()
+}
@@ -0,0 +1,274 @@
+use alloc::{
+	string::{String, ToString},
+	vec::Vec,
+};
+
+use super::{
+	parser::Parser,
+	timing::timing_function,
+	value::{value, Value},
+	CssError,
+};
+use crate::{color::Srgba, functions::EasingDescriptor, AnimationSequence, Keyframe};
+
+/// Animation of a single property in a CSS `@keyframes` rule
+#[derive(Clone, Debug, PartialEq)]
+pub enum CssProperty {
+	/// Property with numbers without a unit, such as `opacity`
+	Number(AnimationSequence<f64>),
+	/// Property with lengths or percentages and the (lowercase) unit shared by all keyframes, such as `px` or `%`
+	Length(AnimationSequence<f64>, String),
+	/// Property with colors, such as `background-color`
+	Color(AnimationSequence<Srgba<f64>>),
+}
+
+/// A CSS `@keyframes` rule split into one animation sequence per property
+#[derive(Clone, Debug, PartialEq)]
+pub struct CssKeyframes {
+	/// The name of the animation
+	pub name: String,
+	/// The animated properties in the order they first appear
+	pub properties: Vec<(String, CssProperty)>,
+}
+
+impl CssKeyframes {
+	/// The animation of a property, if it is animated
+	pub fn property(&self, name: &str) -> Option<&CssProperty> {
+		self.properties
+			.iter()
+			.find(|(property, _)| property == name)
+			.map(|(_, animation)| animation)
+	}
+}
+
+/// Declaration of a property in one keyframe
+struct Declaration {
+	offset: f64,
+	value: Value,
+	function: EasingDescriptor,
+}
+
+/// Parses all `@keyframes` rules in a style sheet, other rules are ignored.
+///
+/// Keyframe selectors (`from`, `to` or percentages) are converted to times between zero and `duration`.
+/// Every keyframe uses its `animation-timing-function` until the next keyframe of the same property, or `ease` like in CSS.
+/// Properties can be animated with numbers, lengths (any unit, including percentages) and colors,
+/// a property that is only declared in some keyframes is only animated between those keyframes.
+///
+/// ```rust
+/// use keyframe::css::{parse_keyframes, CssProperty};
+///
+/// let animations = parse_keyframes("
+///     @keyframes slide-in {
+///         from { left: -100px; opacity: 0; animation-timing-function: ease-out; }
+///         50% { opacity: 1; }
+///         to { left: 0; background-color: #ff0000; }
+///     }
+/// ", 2.0).unwrap();
+///
+/// match animations[0].property("left") {
+///     Some(CssProperty::Length(sequence, unit)) => {
+///         assert_eq!(sequence.duration(), 2.0);
+///         assert_eq!(unit, "px");
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn parse_keyframes(source: &str, duration: f64) -> Result<Vec<CssKeyframes>, CssError> {
+	let mut parser = Parser::new(source);
+	let mut rules = Vec::new();
+
+	loop {
+		parser.skip_whitespace();
+		if parser.is_at_end() {
+			return Ok(rules);
+		}
+
+		if parser.rest().starts_with('@') {
+			let start = parser.position();
+			parser.eat('@');
+			match parser.identifier().map(|name| name.to_ascii_lowercase()).as_deref() {
+				Some("keyframes") | Some("-webkit-keyframes") | Some("-moz-keyframes") => {
+					rules.push(keyframes_rule(&mut parser, duration)?);
+					continue;
+				}
+				_ => parser.reset(start),
+			}
+		}
+
+		// Style rules and other at-rules
+		parser.skip_rule()?;
+	}
+}
+
+fn keyframes_rule(parser: &mut Parser, duration: f64) -> Result<CssKeyframes, CssError> {
+	parser.skip_whitespace();
+	let name = match parser.string() {
+		Some(name) => name,
+		None => parser.identifier().ok_or_else(|| parser.unexpected())?,
+	}
+	.to_string();
+	parser.expect('{')?;
+
+	let mut names: Vec<String> = Vec::new();
+	let mut declarations: Vec<Vec<Declaration>> = Vec::new();
+
+	while !parser.eat('}') {
+		if parser.is_at_end() {
+			return Err(CssError::UnexpectedEnd);
+		}
+
+		let offsets = selectors(parser)?;
+		parser.expect('{')?;
+
+		let mut block: Vec<(String, Value)> = Vec::new();
+		let mut function = None;
+
+		while !parser.eat('}') {
+			if parser.eat(';') {
+				continue;
+			}
+
+			let property = parser.identifier().ok_or_else(|| parser.unexpected())?;
+			// Custom properties are case-sensitive
+			let property = if property.starts_with("--") {
+				property.to_string()
+			} else {
+				property.to_ascii_lowercase()
+			};
+			parser.expect(':')?;
+			let text = parser.until(&[';', '}']).trim();
+
+			// Important declarations are ignored in keyframes
+			if text.to_ascii_lowercase().ends_with("!important") {
+				continue;
+			}
+
+			if property == "animation-timing-function" {
+				let mut timing = Parser::new(text);
+				function = Some(
+					timing_function(&mut timing)
+						.filter(|_| {
+							timing.skip_whitespace();
+							timing.is_at_end()
+						})
+						.ok_or_else(|| CssError::InvalidTimingFunction(text.to_string()))?,
+				);
+			} else {
+				let value = value(text).ok_or_else(|| CssError::InvalidValue {
+					property: property.clone(),
+					value: text.to_string(),
+				})?;
+				block.push((property, value));
+			}
+		}
+
+		let function = function.unwrap_or_else(ease);
+		for (property, value) in block {
+			let index = match names.iter().position(|name| *name == property) {
+				Some(index) => index,
+				None => {
+					names.push(property);
+					declarations.push(Vec::new());
+					names.len() - 1
+				}
+			};
+
+			for &offset in &offsets {
+				let property = &mut declarations[index];
+				// Later declarations for the same keyframe win
+				property.retain(|declaration| declaration.offset != offset);
+				property.push(Declaration {
+					offset,
+					value: value.clone(),
+					function: function.clone(),
+				});
+			}
+		}
+	}
+
+	let properties = names
+		.into_iter()
+		.zip(declarations)
+		.map(|(name, declarations)| {
+			let property = property(&name, declarations, duration)?;
+			Ok((name, property))
+		})
+		.collect::<Result<_, CssError>>()?;
+
+	Ok(CssKeyframes { name, properties })
+}
+
+#[inline]
+fn ease() -> EasingDescriptor {
+	timing_function(&mut Parser::new("ease")).expect("ease is a valid timing function")
+}
+
+/// Parses a list of keyframe selectors as offsets between 0.0 and 1.0
+fn selectors(parser: &mut Parser) -> Result<Vec<f64>, CssError> {
+	let mut offsets = Vec::new();
+
+	loop {
+		let selector = parser.until(&[',', '{']).trim();
+		let offset = match selector.to_ascii_lowercase().as_str() {
+			"from" => Some(0.0),
+			"to" => Some(1.0),
+			_ => {
+				let mut percentage = Parser::new(selector);
+				percentage.percentage().filter(|offset| {
+					(0.0..=1.0).contains(offset) && {
+						percentage.skip_whitespace();
+						percentage.is_at_end()
+					}
+				})
+			}
+		};
+		offsets.push(offset.ok_or_else(|| CssError::InvalidSelector(selector.to_string()))?);
+
+		if !parser.eat(',') {
+			return Ok(offsets);
+		}
+	}
+}
+
+/// Creates the animation sequence of one property, checking that all values have the same type
+fn property(name: &str, declarations: Vec<Declaration>, duration: f64) -> Result<CssProperty, CssError> {
+	let mixed = || CssError::MixedTypes(name.to_string());
+	let unit = declarations.iter().find_map(|declaration| match &declaration.value {
+		Value::Length(_, unit) => Some(unit.clone()),
+		_ => None,
+	});
+
+	fn keyframe<T>(declaration: &Declaration, value: T, duration: f64) -> Keyframe<T> {
+		Keyframe::new(value, declaration.offset * duration, declaration.function.clone())
+	}
+
+	match (&declarations[0].value, unit) {
+		(Value::Color(_), _) => declarations
+			.iter()
+			.map(|declaration| match declaration.value {
+				Value::Color(color) => Ok(keyframe(declaration, color, duration)),
+				_ => Err(mixed()),
+			})
+			.collect::<Result<Vec<_>, _>>()
+			.map(|keyframes| CssProperty::Color(keyframes.into())),
+		(_, Some(unit)) => declarations
+			.iter()
+			.map(|declaration| match &declaration.value {
+				Value::Length(length, other) if *other == unit => Ok(keyframe(declaration, *length, duration)),
+				// Zero lengths don't need a unit
+				Value::Number(number) if *number == 0.0 => Ok(keyframe(declaration, 0.0, duration)),
+				_ => Err(mixed()),
+			})
+			.collect::<Result<Vec<_>, _>>()
+			.map(|keyframes| CssProperty::Length(keyframes.into(), unit)),
+		(_, None) => declarations
+			.iter()
+			.map(|declaration| match declaration.value {
+				Value::Number(number) => Ok(keyframe(declaration, number, duration)),
+				_ => Err(mixed()),
+			})
+			.collect::<Result<Vec<_>, _>>()
+			.map(|keyframes| CssProperty::Number(keyframes.into())),
+	}
+}
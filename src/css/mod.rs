@@ -0,0 +1,57 @@
+//! Import and export of CSS [`@keyframes`](https://developer.mozilla.org/en-US/docs/Web/CSS/@keyframes) rules and
+//! [easing functions](https://www.w3.org/TR/css-easing-2/), for sharing animations with a web front end.
+//!
+//! [`parse_keyframes`] turns each `@keyframes` rule into one [`AnimationSequence`](crate::AnimationSequence) per property
+//! and [`parse_timing_function`] turns values of `animation-timing-function` into easing functions.
+//! [`write_keyframes`] and [`write_timing_function`] do the reverse.
+//!
+//! Only the syntax of `@keyframes` is understood, values are limited to numbers, lengths and colors
+//! (hex, `rgb()`, `hsl()`, `transparent` and the basic color keywords). Other rules in the style sheet are skipped.
+
+use alloc::string::String;
+use core::fmt;
+
+mod export;
+mod keyframes;
+mod parser;
+mod timing;
+mod value;
+
+pub use export::*;
+pub use keyframes::*;
+pub use timing::*;
+
+/// Category of CSS parsing error
+#[derive(Debug)]
+pub enum CssError {
+	/// The input ended before a rule was complete
+	UnexpectedEnd,
+	/// A character that isn't valid at this byte offset
+	UnexpectedCharacter(usize),
+	/// A keyframe selector that isn't `from`, `to` or a percentage between 0% and 100%
+	InvalidSelector(String),
+	/// A timing function that isn't valid or isn't supported
+	InvalidTimingFunction(String),
+	/// A property value that isn't a number, length or color
+	InvalidValue {
+		/// The name of the property
+		property: String,
+		/// The value as it was written
+		value: String,
+	},
+	/// A property with values of different types or lengths with different units
+	MixedTypes(String),
+}
+
+impl fmt::Display for CssError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			CssError::UnexpectedEnd => f.write_str("unexpected end of input"),
+			CssError::UnexpectedCharacter(offset) => write!(f, "unexpected character at byte {}", offset),
+			CssError::InvalidSelector(selector) => write!(f, "invalid keyframe selector `{}`", selector),
+			CssError::InvalidTimingFunction(function) => write!(f, "invalid timing function `{}`", function),
+			CssError::InvalidValue { property, value } => write!(f, "invalid value `{}` for `{}`", value, property),
+			CssError::MixedTypes(property) => write!(f, "`{}` has values of different types", property),
+		}
+	}
+}
@@ -0,0 +1,222 @@
+use super::CssError;
+
+/// Minimal cursor over CSS source text, only understands the syntax needed for `@keyframes` and timing functions
+pub(crate) struct Parser<'a> {
+	source: &'a str,
+	position: usize,
+}
+
+impl<'a> Parser<'a> {
+	pub(crate) fn new(source: &'a str) -> Self {
+		Parser { source, position: 0 }
+	}
+
+	#[inline]
+	pub(crate) fn rest(&self) -> &'a str {
+		&self.source[self.position..]
+	}
+
+	#[inline]
+	pub(crate) fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Goes back to a position returned by `position()`
+	#[inline]
+	pub(crate) fn reset(&mut self, position: usize) {
+		self.position = position;
+	}
+
+	#[inline]
+	pub(crate) fn is_at_end(&self) -> bool {
+		self.position >= self.source.len()
+	}
+
+	#[inline]
+	pub(crate) fn peek(&self) -> Option<char> {
+		self.rest().chars().next()
+	}
+
+	/// Skips whitespace and comments
+	pub(crate) fn skip_whitespace(&mut self) {
+		loop {
+			let rest = self.rest();
+			let trimmed = rest.trim_start();
+			self.position += rest.len() - trimmed.len();
+
+			if trimmed.starts_with("/*") {
+				self.position += trimmed.find("*/").map(|end| end + 2).unwrap_or(trimmed.len());
+			} else {
+				return;
+			}
+		}
+	}
+
+	/// Consumes `c` if it is the next character, after skipping whitespace
+	pub(crate) fn eat(&mut self, c: char) -> bool {
+		self.skip_whitespace();
+		if self.peek() == Some(c) {
+			self.position += c.len_utf8();
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Consumes `c` or fails if it isn't the next character, after skipping whitespace
+	pub(crate) fn expect(&mut self, c: char) -> Result<(), CssError> {
+		if self.eat(c) {
+			Ok(())
+		} else {
+			Err(self.unexpected())
+		}
+	}
+
+	/// Error for the character at the current position
+	pub(crate) fn unexpected(&self) -> CssError {
+		if self.is_at_end() {
+			CssError::UnexpectedEnd
+		} else {
+			CssError::UnexpectedCharacter(self.position)
+		}
+	}
+
+	/// Consumes an identifier such as `ease-in-out`, `--custom` or `px`
+	pub(crate) fn identifier(&mut self) -> Option<&'a str> {
+		self.skip_whitespace();
+		let rest = self.rest();
+
+		let starts_identifier = match rest.strip_prefix('-').unwrap_or(rest).chars().next() {
+			Some(c) => c.is_ascii_alphabetic() || c == '_' || c == '-' || !c.is_ascii(),
+			None => false,
+		};
+		if !starts_identifier {
+			return None;
+		}
+
+		let length = rest
+			.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-' || !c.is_ascii()))
+			.unwrap_or(rest.len());
+		self.position += length;
+		Some(&rest[..length])
+	}
+
+	/// Consumes a string in single or double quotes, escapes are not supported
+	pub(crate) fn string(&mut self) -> Option<&'a str> {
+		self.skip_whitespace();
+		let rest = self.rest();
+		let quote = rest.chars().next().filter(|&c| c == '"' || c == '\'')?;
+
+		let length = rest[1..].find(quote)?;
+		self.position += length + 2;
+		Some(&rest[1..length + 1])
+	}
+
+	/// Consumes a number such as `1`, `-.5` or `1e3`
+	pub(crate) fn number(&mut self) -> Option<f64> {
+		self.skip_whitespace();
+		let bytes = self.rest().as_bytes();
+		let digits = |mut i: usize| {
+			while i < bytes.len() && bytes[i].is_ascii_digit() {
+				i += 1;
+			}
+			i
+		};
+
+		let mut end = if matches!(bytes.first(), Some(b'+') | Some(b'-')) {
+			1
+		} else {
+			0
+		};
+		let integer_end = digits(end);
+		let mut has_digits = integer_end > end;
+		end = integer_end;
+
+		if bytes.get(end) == Some(&b'.') && bytes.get(end + 1).map_or(false, u8::is_ascii_digit) {
+			end = digits(end + 1);
+			has_digits = true;
+		}
+		if !has_digits {
+			return None;
+		}
+
+		if let Some(b'e') | Some(b'E') = bytes.get(end) {
+			let sign = if matches!(bytes.get(end + 1), Some(b'+') | Some(b'-')) {
+				1
+			} else {
+				0
+			};
+			let exponent_end = digits(end + 1 + sign);
+			if exponent_end > end + 1 + sign {
+				end = exponent_end;
+			}
+		}
+
+		let number = self.rest()[..end].parse().ok()?;
+		self.position += end;
+		Some(number)
+	}
+
+	/// Consumes a number immediately followed by `%`, returned as a fraction
+	pub(crate) fn percentage(&mut self) -> Option<f64> {
+		let start = self.position;
+		match self.number() {
+			Some(number) if self.rest().starts_with('%') => {
+				self.position += 1;
+				Some(number / 100.0)
+			}
+			_ => {
+				self.position = start;
+				None
+			}
+		}
+	}
+
+	/// Consumes everything up to (but not including) one of `terminators` outside of parentheses and strings
+	pub(crate) fn until(&mut self, terminators: &[char]) -> &'a str {
+		let rest = self.rest();
+		let mut depth = 0usize;
+		let mut quote = None;
+		let mut end = rest.len();
+
+		for (i, c) in rest.char_indices() {
+			match (quote, c) {
+				(Some(q), _) if c == q => quote = None,
+				(Some(_), _) => {}
+				(None, '"') | (None, '\'') => quote = Some(c),
+				(None, '(') => depth += 1,
+				(None, ')') => depth = depth.saturating_sub(1),
+				(None, _) if depth == 0 && terminators.contains(&c) => {
+					end = i;
+					break;
+				}
+				_ => {}
+			}
+		}
+
+		self.position += end;
+		&rest[..end]
+	}
+
+	/// Skips a rule that isn't understood, such as a style rule or a different at-rule
+	pub(crate) fn skip_rule(&mut self) -> Result<(), CssError> {
+		self.until(&['{', ';']);
+		if self.eat(';') {
+			return Ok(());
+		}
+		self.expect('{')?;
+
+		let mut depth = 1;
+		while depth > 0 {
+			self.until(&['{', '}']);
+			match self.peek() {
+				Some('{') => depth += 1,
+				Some(_) => depth -= 1,
+				None => return Err(CssError::UnexpectedEnd),
+			}
+			self.position += 1;
+		}
+
+		Ok(())
+	}
+}
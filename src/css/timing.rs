@@ -0,0 +1,167 @@
+use alloc::{string::ToString, vec::Vec};
+
+use super::{parser::Parser, CssError};
+use crate::{
+	functions::{BezierCurve, EasingDescriptor, PiecewiseLinear, StepPosition, Steps},
+	Float, Vector2,
+};
+
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64) -> EasingDescriptor {
+	EasingDescriptor::BezierCurve(BezierCurve::from(Vector2 { x: x1, y: y1 }, Vector2 { x: x2, y: y2 }))
+}
+
+/// Parses a CSS [`<easing-function>`](https://www.w3.org/TR/css-easing-2/#easing-functions), such as
+/// `ease-in-out`, `cubic-bezier(0.25, 0.1, 0.25, 1)`, `steps(4, jump-start)` or `linear(0, 0.25 75%, 1)`.
+///
+/// The keywords `ease`, `ease-in`, `ease-out` and `ease-in-out` are the Bézier curves from the CSS specification
+/// and not the sine-based [`EaseIn`](crate::functions::EaseIn), [`EaseOut`](crate::functions::EaseOut) and
+/// [`EaseInOut`](crate::functions::EaseInOut) functions.
+///
+/// ```rust
+/// use keyframe::{css::parse_timing_function, EasingFunction};
+///
+/// let steps = parse_timing_function("steps(4, jump-end)").unwrap();
+/// assert_eq!(steps.y(0.3), 0.25);
+///
+/// let linear = parse_timing_function("linear(0, 0.5 25%, 1)").unwrap();
+/// assert_eq!(linear.y(0.25), 0.5);
+/// ```
+pub fn parse_timing_function(source: &str) -> Result<EasingDescriptor, CssError> {
+	let mut parser = Parser::new(source);
+	let function = timing_function(&mut parser).ok_or_else(|| CssError::InvalidTimingFunction(source.to_string()))?;
+
+	parser.skip_whitespace();
+	if parser.is_at_end() {
+		Ok(function)
+	} else {
+		Err(CssError::InvalidTimingFunction(source.to_string()))
+	}
+}
+
+pub(crate) fn timing_function(parser: &mut Parser) -> Option<EasingDescriptor> {
+	let name = parser.identifier()?.to_ascii_lowercase();
+
+	// Keywords can't be followed by parentheses, functions have to be
+	if !parser.rest().starts_with('(') {
+		return match name.as_str() {
+			"linear" => Some(EasingDescriptor::Linear),
+			"ease" => Some(cubic_bezier(0.25, 0.1, 0.25, 1.0)),
+			"ease-in" => Some(cubic_bezier(0.42, 0.0, 1.0, 1.0)),
+			"ease-out" => Some(cubic_bezier(0.0, 0.0, 0.58, 1.0)),
+			"ease-in-out" => Some(cubic_bezier(0.42, 0.0, 0.58, 1.0)),
+			"step-start" => Some(EasingDescriptor::Steps(Steps::new(1, StepPosition::JumpStart))),
+			"step-end" => Some(EasingDescriptor::Steps(Steps::new(1, StepPosition::JumpEnd))),
+			_ => None,
+		};
+	}
+	parser.expect('(').ok()?;
+
+	let function = match name.as_str() {
+		"cubic-bezier" => {
+			let x1 = parser.number()?;
+			parser.expect(',').ok()?;
+			let y1 = parser.number()?;
+			parser.expect(',').ok()?;
+			let x2 = parser.number()?;
+			parser.expect(',').ok()?;
+			let y2 = parser.number()?;
+
+			if !(0.0..=1.0).contains(&x1) || !(0.0..=1.0).contains(&x2) {
+				return None;
+			}
+			cubic_bezier(x1, y1, x2, y2)
+		}
+		"steps" => {
+			let steps = parser.number()?;
+			let position = if parser.eat(',') {
+				match parser.identifier()?.to_ascii_lowercase().as_str() {
+					"jump-start" | "start" => StepPosition::JumpStart,
+					"jump-end" | "end" => StepPosition::JumpEnd,
+					"jump-none" => StepPosition::JumpNone,
+					"jump-both" => StepPosition::JumpBoth,
+					_ => return None,
+				}
+			} else {
+				StepPosition::JumpEnd
+			};
+
+			let minimum = if position == StepPosition::JumpNone { 2.0 } else { 1.0 };
+			if Float::fract(steps) != 0.0 || steps < minimum || steps > u32::MAX as f64 {
+				return None;
+			}
+			EasingDescriptor::Steps(Steps::new(steps as u32, position))
+		}
+		"linear" => EasingDescriptor::PiecewiseLinear(PiecewiseLinear::new(linear_stops(parser)?)),
+		_ => return None,
+	};
+
+	parser.expect(')').ok()?;
+	Some(function)
+}
+
+/// Parses the stops of `linear()` and fills in missing inputs like described
+/// [here](https://www.w3.org/TR/css-easing-2/#linear-easing-function-parsing)
+fn linear_stops(parser: &mut Parser) -> Option<Vec<[f64; 2]>> {
+	let mut stops: Vec<(f64, Option<f64>)> = Vec::new();
+
+	loop {
+		let output = parser.number()?;
+		let first = parser.percentage();
+		let second = first.and_then(|_| parser.percentage());
+
+		stops.push((output, first));
+		if second.is_some() {
+			stops.push((output, second));
+		}
+
+		if !parser.eat(',') {
+			break;
+		}
+	}
+
+	if stops.len() < 2 {
+		return None;
+	}
+
+	// The first input defaults to 0, the last to 1 (or the largest input if that is larger)
+	let last = stops.len() - 1;
+	if stops[0].1.is_none() {
+		stops[0].1 = Some(0.0);
+	}
+	if stops[last].1.is_none() {
+		let largest = stops.iter().filter_map(|stop| stop.1).fold(1.0, f64::max);
+		stops[last].1 = Some(largest);
+	}
+
+	// Inputs can't decrease, this has to happen before missing inputs are filled in
+	let mut largest = f64::NEG_INFINITY;
+	for stop in stops.iter_mut() {
+		if let Some(input) = stop.1.as_mut() {
+			largest = largest.max(*input);
+			*input = largest;
+		}
+	}
+
+	// Runs of missing inputs are spread evenly between the inputs around them
+	let mut i = 1;
+	while i < last {
+		if stops[i].1.is_some() {
+			i += 1;
+			continue;
+		}
+
+		let start = i - 1;
+		let end = (i..=last).find(|&j| stops[j].1.is_some())?;
+		let (from, to) = (stops[start].1?, stops[end].1?);
+
+		for (j, stop) in stops.iter_mut().enumerate().take(end).skip(i) {
+			stop.1 = Some(from + (to - from) * (j - start) as f64 / (end - start) as f64);
+		}
+		i = end;
+	}
+
+	stops
+		.into_iter()
+		.map(|(output, input)| Some([input?, output]))
+		.collect()
+}
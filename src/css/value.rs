@@ -0,0 +1,178 @@
+use alloc::string::String;
+
+use super::parser::Parser;
+use crate::{
+	color::{Hsla, Srgba},
+	Float,
+};
+
+/// Value of a single declaration in a keyframe
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+	Number(f64),
+	Length(f64, String),
+	Color(Srgba<f64>),
+}
+
+/// Parses a number, a number with a unit or a color, returns `None` for anything else
+pub(crate) fn value(source: &str) -> Option<Value> {
+	if let Some(digits) = source.trim().strip_prefix('#') {
+		return hex_color(digits).map(Value::Color);
+	}
+
+	let mut parser = Parser::new(source);
+	let value = if let Some(number) = parser.number() {
+		if parser.rest().starts_with('%') {
+			parser.eat('%');
+			Value::Length(number, String::from("%"))
+		} else if parser.rest().starts_with(|c: char| c.is_ascii_alphabetic()) {
+			Value::Length(number, parser.identifier()?.to_ascii_lowercase())
+		} else {
+			Value::Number(number)
+		}
+	} else {
+		let name = parser.identifier()?.to_ascii_lowercase();
+		if parser.eat('(') {
+			Value::Color(color_function(&name, &mut parser)?)
+		} else {
+			Value::Color(named_color(&name)?)
+		}
+	};
+
+	parser.skip_whitespace();
+	if parser.is_at_end() {
+		Some(value)
+	} else {
+		None
+	}
+}
+
+fn hex_color(digits: &str) -> Option<Srgba<f64>> {
+	if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+		return None;
+	}
+
+	let digit = |i: usize| u8::from_str_radix(digits.get(i..=i)?, 16).ok();
+	let pair = |i: usize| u8::from_str_radix(digits.get(i..i + 2)?, 16).ok();
+
+	let rgba = match digits.len() {
+		3 | 4 => {
+			let short = |i: usize| digit(i).map(|d| d * 17);
+			[
+				short(0)?,
+				short(1)?,
+				short(2)?,
+				if digits.len() == 4 { short(3)? } else { 255 },
+			]
+		}
+		6 | 8 => [
+			pair(0)?,
+			pair(2)?,
+			pair(4)?,
+			if digits.len() == 8 { pair(6)? } else { 255 },
+		],
+		_ => return None,
+	};
+
+	Some(Srgba::from_rgba8(rgba))
+}
+
+/// Parses the arguments of `rgb()`, `rgba()`, `hsl()` or `hsla()` with both the comma and space separated syntax
+fn color_function(name: &str, parser: &mut Parser) -> Option<Srgba<f64>> {
+	let mut components = [1.0; 4];
+	let mut count = 0;
+	let mut commas = None;
+
+	while count < 4 && !parser.eat(')') {
+		if count > 0 {
+			// Either all components are separated by commas or alpha is separated with a slash
+			let comma = parser.eat(',');
+			match commas {
+				None => commas = Some(comma),
+				Some(commas) if commas != comma => return None,
+				_ => {}
+			}
+			if !comma && count == 3 && !parser.eat('/') {
+				return None;
+			}
+		}
+
+		let is_hue = name.starts_with("hsl") && count == 0;
+		components[count] = match parser.percentage() {
+			Some(_) if is_hue => return None,
+			Some(percentage) => percentage,
+			None => {
+				let number = parser.number()?;
+				match count {
+					_ if is_hue => match parser.identifier().map(|unit| unit.to_ascii_lowercase()).as_deref() {
+						None | Some("deg") => number,
+						Some("grad") => number * 0.9,
+						Some("rad") => Float::to_degrees(number),
+						Some("turn") => number * 360.0,
+						_ => return None,
+					},
+					3 => number,
+					_ if name.starts_with("rgb") => number / 255.0,
+					// Saturation and lightness have to be percentages
+					_ => return None,
+				}
+			}
+		};
+		count += 1;
+
+		if count == 4 {
+			parser.expect(')').ok()?;
+		}
+	}
+
+	if count < 3 {
+		return None;
+	}
+
+	let [a, b, c, alpha] = components;
+	let alpha = alpha.clamp(0.0, 1.0);
+	match name {
+		"rgb" | "rgba" => Some(Srgba::new(
+			a.clamp(0.0, 1.0),
+			b.clamp(0.0, 1.0),
+			c.clamp(0.0, 1.0),
+			alpha,
+		)),
+		"hsl" | "hsla" => Some(
+			Hsla::new(
+				((a % 360.0) + 360.0) % 360.0,
+				b.clamp(0.0, 1.0),
+				c.clamp(0.0, 1.0),
+				alpha,
+			)
+			.into(),
+		),
+		_ => None,
+	}
+}
+
+fn named_color(name: &str) -> Option<Srgba<f64>> {
+	let rgba = match name {
+		"transparent" => [0, 0, 0, 0],
+		"black" => [0, 0, 0, 255],
+		"silver" => [192, 192, 192, 255],
+		"gray" | "grey" => [128, 128, 128, 255],
+		"white" => [255, 255, 255, 255],
+		"maroon" => [128, 0, 0, 255],
+		"red" => [255, 0, 0, 255],
+		"purple" => [128, 0, 128, 255],
+		"fuchsia" | "magenta" => [255, 0, 255, 255],
+		"green" => [0, 128, 0, 255],
+		"lime" => [0, 255, 0, 255],
+		"olive" => [128, 128, 0, 255],
+		"yellow" => [255, 255, 0, 255],
+		"navy" => [0, 0, 128, 255],
+		"blue" => [0, 0, 255, 255],
+		"teal" => [0, 128, 128, 255],
+		"aqua" | "cyan" => [0, 255, 255, 255],
+		"orange" => [255, 165, 0, 255],
+		_ => return None,
+	};
+
+	Some(Srgba::from_rgba8(rgba))
+}
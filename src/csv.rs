@@ -0,0 +1,336 @@
+//! CSV and TSV tables of animation sequences for spreadsheets, plotting and analysis.
+//!
+//! [`write_samples`] samples a sequence at a fixed rate and writes one row per sample, with a column for the time and
+//! one column per component of the value (`x`, `y`, ... for mint types and `value_1`, `value_2`, ... for arrays).
+//!
+//! [`write_table`] and [`parse_table`] write and read the keyframes themselves, with an `easing` column that contains
+//! the easing function as it is written in the [text format](crate::text), e.g. `ease-in-out` or `steps(4, 0, 1)`.
+//! Fields that contain the delimiter are quoted like in [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180).
+//!
+//! ```rust
+//! use keyframe::{csv::{parse_table, write_samples}, mint::Vector2, AnimationSequence};
+//!
+//! let sequence: AnimationSequence<Vector2<f64>> = parse_table("
+//! time,x,y,easing
+//! 0.0,0.0,0.0,linear
+//! 1.0,2.0,1.0,
+//! ", ',').unwrap();
+//!
+//! assert_eq!(write_samples(&sequence, 2.0, ',').unwrap(), "time,x,y\n0.0,0.0,0.0\n0.5,1.0,0.5\n1.0,2.0,1.0\n");
+//! assert!(write_samples(&sequence, 0.0, ',').is_err());
+//! // The first line is only a header if none of its fields are numbers
+//! assert_eq!(parse_table::<f64>("0..5,1.0\n", ',').unwrap_err().line, 1);
+//! ```
+
+use alloc::{
+	borrow::Cow,
+	string::{String, ToString},
+	vec::Vec,
+};
+
+use crate::{
+	functions::EaseInOut,
+	text::{parse_function, write_function, write_number, TextError, TextErrorKind, TextValue},
+	AnimationSequence, CanTween, EasingRegistry, Keyframe,
+};
+
+/// Writes the value of a sequence at a fixed rate, from the start to the end of the sequence.
+/// The last row is always at the end of the sequence, even if the duration is not a multiple of the sample period.
+///
+/// # Arguments
+///
+/// * `sequence` - The sequence to sample, its current time is not changed
+/// * `rate` - Samples per second, fails with [`TextErrorKind::InvalidSampleRate`] if it is not positive and finite
+/// * `delimiter` - The character between fields, usually `,` or `\t`
+pub fn write_samples<T: TextValue + CanTween + Clone>(
+	sequence: &AnimationSequence<T>,
+	rate: f64,
+	delimiter: char,
+) -> Result<String, TextError> {
+	if !(rate > 0.0 && rate.is_finite()) {
+		return Err(TextError {
+			line: 0,
+			column: 0,
+			kind: TextErrorKind::InvalidSampleRate(rate),
+		});
+	}
+
+	let mut text = String::new();
+	let mut separator = [0; 4];
+	let separator = &*delimiter.encode_utf8(&mut separator);
+
+	write_header(&mut text, sequence, separator);
+	text.push('\n');
+
+	if sequence.keyframes() == 0 {
+		return Ok(text);
+	}
+
+	let mut sampler = sequence.clone();
+	let duration = sequence.duration();
+	let mut write_row = |time: f64| {
+		sampler.advance_to(time);
+		if let Some(value) = sampler.now_strict() {
+			write_number(&mut text, time);
+			text.push_str(separator);
+			value.write_components(&mut text, separator);
+			text.push('\n');
+		}
+	};
+
+	let mut i = 0;
+	loop {
+		let time = i as f64 / rate;
+		if time >= duration {
+			break;
+		}
+		write_row(time);
+		i += 1;
+	}
+	write_row(duration);
+
+	Ok(text)
+}
+
+/// Writes the keyframes of a sequence with a header and one row per keyframe.
+/// Fails if a keyframe has a user-defined easing function without a [`EasingDescriptor`](crate::functions::EasingDescriptor),
+/// the line of the error is the line that the keyframe would have been written to.
+pub fn write_table<T: TextValue>(sequence: &AnimationSequence<T>, delimiter: char) -> Result<String, TextError> {
+	let mut text = String::new();
+	let mut separator = [0; 4];
+	let separator = &*delimiter.encode_utf8(&mut separator);
+
+	write_header(&mut text, sequence, separator);
+	text.push_str(separator);
+	text.push_str("easing\n");
+
+	for (i, keyframe) in sequence.into_iter().enumerate() {
+		let start = text.len();
+		write_number(&mut text, keyframe.time());
+		text.push_str(separator);
+		keyframe.value.write_components(&mut text, separator);
+		text.push_str(separator);
+
+		let mut function = String::new();
+		if !write_function(&mut function, keyframe.function()) {
+			return Err(TextError {
+				line: i + 2,
+				column: text[start..].chars().count() + 1,
+				kind: TextErrorKind::UserDefinedFunction,
+			});
+		}
+
+		if function.contains(delimiter) || function.contains('"') {
+			text.push('"');
+			text.push_str(&function.replace('"', "\"\""));
+			text.push('"');
+		} else {
+			text.push_str(&function);
+		}
+		text.push('\n');
+	}
+
+	Ok(text)
+}
+
+/// Reads a table of keyframes with the built-in easing functions
+#[inline]
+pub fn parse_table<T: TextValue>(source: &str, delimiter: char) -> Result<AnimationSequence<T>, TextError> {
+	parse_table_with(source, delimiter, &EasingRegistry::new())
+}
+
+/// Reads a table of keyframes with the easing functions in a registry.
+///
+/// The first column is the time in seconds and the other columns are the components of the value. If the first line
+/// is a header (none of its fields are numbers), a column named `easing` or `function` contains the easing functions.
+/// A first line with some numbers is read as a keyframe, so a typo in it is reported instead of skipped.
+/// Without a header, the last field of a row is the easing function if it is empty or starts with a letter.
+/// Keyframes with an empty easing field use `ease-in-out`. Empty lines and lines starting with `#` are ignored.
+pub fn parse_table_with<T: TextValue>(
+	source: &str,
+	delimiter: char,
+	registry: &EasingRegistry,
+) -> Result<AnimationSequence<T>, TextError> {
+	let mut sequence = AnimationSequence::new();
+	// Index of the easing column if there is a header
+	let mut header: Option<Option<usize>> = None;
+	let mut first_row = true;
+
+	for (i, line) in source.lines().enumerate() {
+		let trimmed = line.trim();
+		if trimmed.is_empty() || trimmed.starts_with('#') {
+			continue;
+		}
+
+		let fields = fields(line, i + 1, delimiter)?;
+		if first_row && fields.iter().all(|field| field.text.parse::<f64>().is_err()) {
+			first_row = false;
+			header = Some(fields.iter().position(|field| {
+				field.text.eq_ignore_ascii_case("easing") || field.text.eq_ignore_ascii_case("function")
+			}));
+			continue;
+		}
+
+		first_row = false;
+
+		let easing = match header {
+			Some(column) => column,
+			None => {
+				let last = fields.len() - 1;
+				let name = fields[last]
+					.text
+					.chars()
+					.next()
+					.map_or(true, |c| c.is_ascii_alphabetic());
+				Some(last).filter(|&last| last > 0 && name)
+			}
+		};
+
+		let error = |field: &Field, kind| TextError {
+			line: i + 1,
+			column: field.column,
+			kind,
+		};
+		let number = |field: &Field| {
+			field
+				.text
+				.parse::<f64>()
+				.map_err(|_| error(field, TextErrorKind::Expected("a number")))
+		};
+
+		let time = number(&fields[0])?;
+		if !time.is_finite() || time < 0.0 {
+			return Err(error(&fields[0], TextErrorKind::InvalidTime));
+		}
+
+		let components = fields
+			.iter()
+			.enumerate()
+			.skip(1)
+			.filter(|&(j, _)| Some(j) != easing)
+			.map(|(_, field)| number(field))
+			.collect::<Result<Vec<_>, _>>()?;
+		let value_field = fields.get(1).unwrap_or(&fields[0]);
+		let value = T::from_components(&components)
+			.ok_or_else(|| error(value_field, TextErrorKind::InvalidValue(components.len())))?;
+
+		let keyframe = match easing
+			.and_then(|j| fields.get(j))
+			.filter(|field| !field.text.is_empty())
+		{
+			Some(field) => {
+				Keyframe::new_dynamic(value, time, parse_function(&field.text, i + 1, field.column, registry)?)
+			}
+			None => Keyframe::new(value, time, EaseInOut),
+		};
+
+		sequence
+			.insert(keyframe)
+			.map_err(|_| error(&fields[0], TextErrorKind::TimeCollision(time)))?;
+	}
+
+	Ok(sequence)
+}
+
+/// Writes `time` and the names of the components of the first keyframe
+fn write_header<T: TextValue>(out: &mut String, sequence: &AnimationSequence<T>, separator: &str) {
+	out.push_str("time");
+
+	let names = T::component_names();
+	let components = match sequence.into_iter().next() {
+		Some(keyframe) => {
+			let mut components = String::new();
+			keyframe.value.write_components(&mut components, ",");
+			components.matches(',').count() + 1
+		}
+		None => names.len().max(1),
+	};
+
+	for i in 0..components {
+		out.push_str(separator);
+		match names.get(i) {
+			Some(name) if names.len() == components => out.push_str(name),
+			_ if components == 1 => out.push_str("value"),
+			_ => {
+				out.push_str("value_");
+				out.push_str(&(i + 1).to_string());
+			}
+		}
+	}
+}
+
+/// Field of a row without surrounding whitespace and quotes
+struct Field<'a> {
+	text: Cow<'a, str>,
+	column: usize,
+}
+
+/// Splits a line into fields, fields in quotes can contain the delimiter and `""` for a quote
+fn fields(line: &str, number: usize, delimiter: char) -> Result<Vec<Field<'_>>, TextError> {
+	let mut fields = Vec::new();
+	let mut chars = line.char_indices().peekable();
+
+	loop {
+		while let Some(&(_, c)) = chars.peek() {
+			if c == delimiter || !c.is_whitespace() {
+				break;
+			}
+			chars.next();
+		}
+
+		let start = chars.peek().map_or(line.len(), |&(i, _)| i);
+		let mut column = line[..start].chars().count() + 1;
+
+		let text = if chars.peek().map(|&(_, c)| c) == Some('"') {
+			// Errors in the text of the field point after the quote
+			chars.next();
+			column += 1;
+			let mut text = String::new();
+			loop {
+				match chars.next() {
+					Some((_, '"')) if chars.peek().map(|&(_, c)| c) == Some('"') => {
+						chars.next();
+						text.push('"');
+					}
+					Some((_, '"')) => break,
+					Some((_, c)) => text.push(c),
+					None => {
+						return Err(TextError {
+							line: number,
+							column: line.chars().count() + 1,
+							kind: TextErrorKind::Expected("`\"`"),
+						})
+					}
+				}
+			}
+			Cow::Owned(text)
+		} else {
+			let end = line[start..].find(delimiter).map_or(line.len(), |end| start + end);
+			while chars.peek().map_or(false, |&(i, _)| i < end) {
+				chars.next();
+			}
+			Cow::Borrowed(line[start..end].trim_end())
+		};
+
+		fields.push(Field { text, column });
+
+		while let Some(&(_, c)) = chars.peek() {
+			if c == delimiter || !c.is_whitespace() {
+				break;
+			}
+			chars.next();
+		}
+
+		match chars.next() {
+			Some((_, c)) if c == delimiter => {}
+			None => return Ok(fields),
+			Some((i, _)) => {
+				return Err(TextError {
+					line: number,
+					column: line[..i].chars().count() + 1,
+					kind: TextErrorKind::Expected("a delimiter"),
+				})
+			}
+		}
+	}
+}
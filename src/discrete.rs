@@ -0,0 +1,94 @@
+use crate::{as_f64, CanTween, Float};
+
+// Point in time at which values without a configurable threshold switch from one value to the other
+const DEFAULT_THRESHOLD: f64 = 0.5;
+
+#[inline]
+pub(crate) fn switch<T>(from: T, to: T, time: impl Float, threshold: f64) -> T {
+	if as_f64(time) < threshold {
+		from
+	} else {
+		to
+	}
+}
+
+impl CanTween for bool {
+	/// Switches from `from` to `to` half-way through
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		switch(from, to, time, DEFAULT_THRESHOLD)
+	}
+}
+
+impl<T: CanTween> CanTween for Option<T> {
+	/// Interpolates the values if both are `Some`, otherwise switches from `from` to `to` half-way through
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		match (from, to) {
+			(Some(from), Some(to)) => Some(T::ease(from, to, time)),
+			(from, to) => switch(from, to, time, DEFAULT_THRESHOLD),
+		}
+	}
+}
+
+/// Value that switches from one value to another instead of being interpolated, e.g. an enum or a visibility flag
+///
+/// ```rust
+/// use keyframe::{ease, functions::Linear, Discrete};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// enum State {
+///     Idle,
+///     Running,
+/// }
+///
+/// let from = Discrete::with_threshold(State::Idle, 0.25);
+/// let to = Discrete::new(State::Running);
+///
+/// assert_eq!(ease(Linear, from, to, 0.2).value, State::Idle);
+/// assert_eq!(ease(Linear, from, to, 0.3).value, State::Running);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Discrete<T> {
+	/// The value
+	pub value: T,
+	/// The point in time (0.0 to 1.0) at which tweening from this value switches to the next value
+	pub threshold: f64,
+}
+
+impl<T> Discrete<T> {
+	/// Creates a new discrete value that switches to the next value half-way through
+	#[inline]
+	pub fn new(value: T) -> Self {
+		Discrete::with_threshold(value, DEFAULT_THRESHOLD)
+	}
+
+	/// Creates a new discrete value that switches to the next value at the specified point in time (0.0 to 1.0)
+	#[inline]
+	pub fn with_threshold(value: T, threshold: f64) -> Self {
+		Discrete { value, threshold }
+	}
+}
+
+impl<T: Default> Default for Discrete<T> {
+	#[inline]
+	fn default() -> Self {
+		Discrete::new(T::default())
+	}
+}
+
+impl<T> From<T> for Discrete<T> {
+	#[inline]
+	fn from(value: T) -> Self {
+		Discrete::new(value)
+	}
+}
+
+impl<T> CanTween for Discrete<T> {
+	/// Switches from `from` to `to` at the threshold of `from`
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		let threshold = from.threshold;
+		switch(from, to, time, threshold)
+	}
+}
@@ -19,6 +19,13 @@ pub trait EasingFunction {
 	/// Since a static curve function will have zero size the size of a `dyn EasingFunction` will be the same size as a vtable.
 	/// This also means you can specify a static curve function with only the name of the type (e.g. `ease(EaseInOut, 0.0, 1.0, 0.5)`).
 	fn y(&self, x: f64) -> f64;
+
+	/// Describes this function if it is one of the built-in functions, see [`EasingDescriptor`].
+	/// User-defined functions return `None` unless they override this method.
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		None
+	}
 }
 
 /// Type that can be used with an easing function
@@ -27,7 +34,21 @@ pub trait CanTween {
 	///
 	/// # Note
 	/// This function will always create a new value, so calling it on very large structures is not a good idea.
+	/// Use [`ease_into`](#method.ease_into) instead to reuse an existing value.
 	fn ease(from: Self, to: Self, time: impl Float) -> Self;
+
+	/// Writes the interpolated value between `from` and `to` at the specified time into `out`.
+	///
+	/// # Note
+	/// The default implementation clones `from` and `to` and calls [`ease`](#method.ease).
+	/// Types that own large buffers (e.g. `Vec<T>`) override this to tween their elements in place.
+	#[inline]
+	fn ease_into(from: &Self, to: &Self, time: impl Float, out: &mut Self)
+	where
+		Self: Clone,
+	{
+		*out = Self::ease(from.clone(), to.clone(), time);
+	}
 }
 
 impl CanTween for f32 {
@@ -68,6 +89,30 @@ impl<T: CanTween, const N: usize> CanTween for [T; N] {
 	}
 }
 
+macro_rules! impl_can_tween_for_tuple {
+	($($name: ident $index: tt),+) => {
+		impl<$($name: CanTween),+> CanTween for ($($name,)+) {
+			#[inline]
+			fn ease(from: Self, to: Self, time: impl Float) -> Self {
+				($($name::ease(from.$index, to.$index, time),)+)
+			}
+		}
+	};
+}
+
+impl_can_tween_for_tuple!(A 0);
+impl_can_tween_for_tuple!(A 0, B 1);
+impl_can_tween_for_tuple!(A 0, B 1, C 2);
+impl_can_tween_for_tuple!(A 0, B 1, C 2, D 3);
+impl_can_tween_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_can_tween_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_can_tween_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_can_tween_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_can_tween_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_can_tween_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_can_tween_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_can_tween_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
 /// Returns the value at a specified X position on the curve between point A and point B.
 /// The time argument is expected to stay within a range of 0.0 to 1.0 but bounds checking is not enforced.
 #[inline]
@@ -121,6 +166,12 @@ pub fn ease_with_scaled_time<V: CanTween, T: Float, F: EasingFunction>(
 #[cfg(feature = "mint_types")]
 mod mint_type_impls {
 	use crate::easing::*;
+	use crate::rotation::{self, EulerOrder};
+	use crate::transform::{self, transpose};
+	use mint::{
+		ColumnMatrix2, ColumnMatrix2x3, ColumnMatrix3, ColumnMatrix3x4, ColumnMatrix4, ExtraXYZ, ExtraZXZ, ExtraZYX,
+		IntraXYZ, IntraZXZ, IntraZYX, RowMatrix2, RowMatrix2x3, RowMatrix3, RowMatrix3x4, RowMatrix4,
+	};
 
 	impl<V: CanTween> CanTween for Vector2<V> {
 		#[inline]
@@ -175,4 +226,180 @@ mod mint_type_impls {
 			}
 		}
 	}
+
+	impl<V: Float> CanTween for Quaternion<V> {
+		/// Spherical linear interpolation (slerp) between two rotations.
+		/// The shortest path is always taken and the returned quaternion is normalized.
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			let convert = |q: Self| [as_f64(q.v.x), as_f64(q.v.y), as_f64(q.v.z), as_f64(q.s)];
+			let [x, y, z, s] = rotation::slerp(convert(from), convert(to), as_f64(time));
+
+			Self::from([as_t(x), as_t(y), as_t(z), as_t(s)])
+		}
+	}
+
+	// Euler angles are converted to quaternions, interpolated with slerp and then converted back.
+	// This means the angles of the returned value are always in their canonical range.
+	fn ease_euler<V: Float, B>(
+		from: EulerAngles<V, B>,
+		to: EulerAngles<V, B>,
+		time: impl Float,
+		order: EulerOrder,
+		extrinsic: bool,
+	) -> EulerAngles<V, B> {
+		// Extrinsic rotations are intrinsic rotations in the reverse order
+		let convert = |e: EulerAngles<V, B>| {
+			let angles = [as_f64(e.a), as_f64(e.b), as_f64(e.c)];
+			rotation::from_euler(
+				order,
+				if extrinsic {
+					[angles[2], angles[1], angles[0]]
+				} else {
+					angles
+				},
+			)
+		};
+
+		let [a, b, c] = rotation::to_euler(order, rotation::slerp(convert(from), convert(to), as_f64(time)));
+		let angles = if extrinsic { [c, b, a] } else { [a, b, c] };
+
+		EulerAngles::from([as_t(angles[0]), as_t(angles[1]), as_t(angles[2])])
+	}
+
+	impl<V: Float> CanTween for EulerAngles<V, IntraXYZ> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			ease_euler(from, to, time, EulerOrder::IntrinsicXYZ, false)
+		}
+	}
+
+	impl<V: Float> CanTween for EulerAngles<V, IntraZXZ> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			ease_euler(from, to, time, EulerOrder::IntrinsicZXZ, false)
+		}
+	}
+
+	impl<V: Float> CanTween for EulerAngles<V, IntraZYX> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			ease_euler(from, to, time, EulerOrder::IntrinsicZYX, false)
+		}
+	}
+
+	impl<V: Float> CanTween for EulerAngles<V, ExtraXYZ> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			ease_euler(from, to, time, EulerOrder::IntrinsicZYX, true)
+		}
+	}
+
+	impl<V: Float> CanTween for EulerAngles<V, ExtraZXZ> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			ease_euler(from, to, time, EulerOrder::IntrinsicZXZ, true)
+		}
+	}
+
+	impl<V: Float> CanTween for EulerAngles<V, ExtraZYX> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			ease_euler(from, to, time, EulerOrder::IntrinsicXYZ, true)
+		}
+	}
+
+	// Matrices are decomposed into translation, rotation, scale and skew which are interpolated separately.
+	// 2x2 and 3x3 matrices are linear transforms, 2x3 and 3x4 matrices are affine transforms (with translation in the last column)
+	// and 4x4 matrices are projective transforms.
+
+	impl<V: Float> CanTween for ColumnMatrix2<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transform::ease_linear_2d(from.into(), to.into(), time))
+		}
+	}
+
+	impl<V: Float> CanTween for RowMatrix2<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transpose(transform::ease_linear_2d(
+				transpose(from.into()),
+				transpose(to.into()),
+				time,
+			)))
+		}
+	}
+
+	impl<V: Float> CanTween for ColumnMatrix2x3<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transform::ease_affine_2d(from.into(), to.into(), time))
+		}
+	}
+
+	impl<V: Float> CanTween for RowMatrix2x3<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transpose(transform::ease_affine_2d(
+				transpose(from.into()),
+				transpose(to.into()),
+				time,
+			)))
+		}
+	}
+
+	impl<V: Float> CanTween for ColumnMatrix3<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transform::ease_linear_3d(from.into(), to.into(), time))
+		}
+	}
+
+	impl<V: Float> CanTween for RowMatrix3<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transpose(transform::ease_linear_3d(
+				transpose(from.into()),
+				transpose(to.into()),
+				time,
+			)))
+		}
+	}
+
+	impl<V: Float> CanTween for ColumnMatrix3x4<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transform::ease_affine_3d(from.into(), to.into(), time))
+		}
+	}
+
+	impl<V: Float> CanTween for RowMatrix3x4<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transpose(transform::ease_affine_3d(
+				transpose(from.into()),
+				transpose(to.into()),
+				time,
+			)))
+		}
+	}
+
+	impl<V: Float> CanTween for ColumnMatrix4<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transform::ease_projective_3d(from.into(), to.into(), time))
+		}
+	}
+
+	impl<V: Float> CanTween for RowMatrix4<V> {
+		#[inline]
+		fn ease(from: Self, to: Self, time: impl Float) -> Self {
+			Self::from(transpose(transform::ease_projective_3d(
+				transpose(from.into()),
+				transpose(to.into()),
+				time,
+			)))
+		}
+	}
 }
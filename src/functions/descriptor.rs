@@ -0,0 +1,194 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{functions::*, EasingFunction};
+
+/// Description of a built-in easing function and its parameters.
+///
+/// Every built-in function can be turned into a descriptor (see [`EasingFunction::descriptor`]) and the descriptor
+/// is itself an easing function, which means keyframes can be saved and loaded with the `serde` feature.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EasingDescriptor {
+	/// [`Linear`]
+	Linear,
+	/// [`Step`]
+	Step,
+	/// [`Hold`]
+	Hold,
+	/// [`EaseInQuad`]
+	EaseInQuad,
+	/// [`EaseOutQuad`]
+	EaseOutQuad,
+	/// [`EaseInOutQuad`]
+	EaseInOutQuad,
+	/// [`EaseInCubic`]
+	EaseInCubic,
+	/// [`EaseOutCubic`]
+	EaseOutCubic,
+	/// [`EaseInOutCubic`]
+	EaseInOutCubic,
+	/// [`EaseInQuart`]
+	EaseInQuart,
+	/// [`EaseOutQuart`]
+	EaseOutQuart,
+	/// [`EaseInOutQuart`]
+	EaseInOutQuart,
+	/// [`EaseInQuint`]
+	EaseInQuint,
+	/// [`EaseOutQuint`]
+	EaseOutQuint,
+	/// [`EaseInOutQuint`]
+	EaseInOutQuint,
+	/// [`EaseIn`]
+	EaseIn,
+	/// [`EaseOut`]
+	EaseOut,
+	/// [`EaseInOut`]
+	EaseInOut,
+	/// [`BezierCurve`] with its control points
+	#[cfg(feature = "mint_types")]
+	BezierCurve(BezierCurve),
+	/// [`Keyframes`] with its sample table
+	Keyframes(Keyframes),
+	/// [`Steps`] with its number of intervals and step position
+	Steps(Steps),
+	/// [`PiecewiseLinear`] with its points
+	#[cfg(feature = "alloc")]
+	PiecewiseLinear(PiecewiseLinear),
+}
+
+impl EasingDescriptor {
+	/// The name of this function, which is the same name that it has in an [`EasingRegistry`](crate::EasingRegistry)
+	pub fn name(&self) -> &'static str {
+		match self {
+			EasingDescriptor::Linear => "Linear",
+			EasingDescriptor::Step => "Step",
+			EasingDescriptor::Hold => "Hold",
+			EasingDescriptor::EaseInQuad => "EaseInQuad",
+			EasingDescriptor::EaseOutQuad => "EaseOutQuad",
+			EasingDescriptor::EaseInOutQuad => "EaseInOutQuad",
+			EasingDescriptor::EaseInCubic => "EaseInCubic",
+			EasingDescriptor::EaseOutCubic => "EaseOutCubic",
+			EasingDescriptor::EaseInOutCubic => "EaseInOutCubic",
+			EasingDescriptor::EaseInQuart => "EaseInQuart",
+			EasingDescriptor::EaseOutQuart => "EaseOutQuart",
+			EasingDescriptor::EaseInOutQuart => "EaseInOutQuart",
+			EasingDescriptor::EaseInQuint => "EaseInQuint",
+			EasingDescriptor::EaseOutQuint => "EaseOutQuint",
+			EasingDescriptor::EaseInOutQuint => "EaseInOutQuint",
+			EasingDescriptor::EaseIn => "EaseIn",
+			EasingDescriptor::EaseOut => "EaseOut",
+			EasingDescriptor::EaseInOut => "EaseInOut",
+			#[cfg(feature = "mint_types")]
+			EasingDescriptor::BezierCurve(_) => "BezierCurve",
+			EasingDescriptor::Keyframes(_) => "Keyframes",
+			EasingDescriptor::Steps(_) => "Steps",
+			#[cfg(feature = "alloc")]
+			EasingDescriptor::PiecewiseLinear(_) => "PiecewiseLinear",
+		}
+	}
+
+	/// The parameters of this function in the order an [`EasingRegistry`](crate::EasingRegistry) expects them.
+	/// Functions without parameters return an empty list.
+	#[cfg(feature = "alloc")]
+	pub fn parameters(&self) -> Vec<f64> {
+		match self {
+			#[cfg(feature = "mint_types")]
+			EasingDescriptor::BezierCurve(curve) => {
+				let (p1, p2) = (curve.p1(), curve.p2());
+				[p1.x, p1.y, p2.x, p2.y].iter().map(|&v| v as f64).collect()
+			}
+			EasingDescriptor::Keyframes(keyframes) => keyframes.sample_table().to_vec(),
+			EasingDescriptor::Steps(steps) => {
+				let position = steps.position();
+				let flag = |jump: bool| if jump { 1.0 } else { 0.0 };
+				[
+					steps.steps() as f64,
+					flag(position.jumps_at_start()),
+					flag(position.jumps_at_end()),
+				]
+				.to_vec()
+			}
+			EasingDescriptor::PiecewiseLinear(linear) => linear.points().iter().flatten().copied().collect(),
+			_ => Vec::new(),
+		}
+	}
+}
+
+impl fmt::Display for EasingDescriptor {
+	/// Formats the function like a function call, e.g. `EaseInOut` or `BezierCurve(0.25, 0.1, 0.25, 1)`
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.name())?;
+
+		match self {
+			#[cfg(feature = "mint_types")]
+			EasingDescriptor::BezierCurve(curve) => {
+				let (p1, p2) = (curve.p1(), curve.p2());
+				write!(f, "({}, {}, {}, {})", p1.x, p1.y, p2.x, p2.y)
+			}
+			EasingDescriptor::Keyframes(keyframes) => {
+				for (i, sample) in keyframes.sample_table().iter().enumerate() {
+					write!(f, "{}{}", if i == 0 { "(" } else { ", " }, sample)?;
+				}
+				f.write_str(")")
+			}
+			EasingDescriptor::Steps(steps) => {
+				let position = steps.position();
+				write!(
+					f,
+					"({}, {}, {})",
+					steps.steps(),
+					position.jumps_at_start() as u8,
+					position.jumps_at_end() as u8
+				)
+			}
+			#[cfg(feature = "alloc")]
+			EasingDescriptor::PiecewiseLinear(linear) => {
+				for (i, [input, output]) in linear.points().iter().enumerate() {
+					write!(f, "{}{}, {}", if i == 0 { "(" } else { ", " }, input, output)?;
+				}
+				f.write_str(")")
+			}
+			_ => Ok(()),
+		}
+	}
+}
+
+impl EasingFunction for EasingDescriptor {
+	fn y(&self, x: f64) -> f64 {
+		match self {
+			EasingDescriptor::Linear => Linear.y(x),
+			EasingDescriptor::Step => Step.y(x),
+			EasingDescriptor::Hold => Hold.y(x),
+			EasingDescriptor::EaseInQuad => EaseInQuad.y(x),
+			EasingDescriptor::EaseOutQuad => EaseOutQuad.y(x),
+			EasingDescriptor::EaseInOutQuad => EaseInOutQuad.y(x),
+			EasingDescriptor::EaseInCubic => EaseInCubic.y(x),
+			EasingDescriptor::EaseOutCubic => EaseOutCubic.y(x),
+			EasingDescriptor::EaseInOutCubic => EaseInOutCubic.y(x),
+			EasingDescriptor::EaseInQuart => EaseInQuart.y(x),
+			EasingDescriptor::EaseOutQuart => EaseOutQuart.y(x),
+			EasingDescriptor::EaseInOutQuart => EaseInOutQuart.y(x),
+			EasingDescriptor::EaseInQuint => EaseInQuint.y(x),
+			EasingDescriptor::EaseOutQuint => EaseOutQuint.y(x),
+			EasingDescriptor::EaseInOutQuint => EaseInOutQuint.y(x),
+			EasingDescriptor::EaseIn => EaseIn.y(x),
+			EasingDescriptor::EaseOut => EaseOut.y(x),
+			EasingDescriptor::EaseInOut => EaseInOut.y(x),
+			#[cfg(feature = "mint_types")]
+			EasingDescriptor::BezierCurve(curve) => curve.y(x),
+			EasingDescriptor::Keyframes(keyframes) => keyframes.y(x),
+			EasingDescriptor::Steps(steps) => steps.y(x),
+			#[cfg(feature = "alloc")]
+			EasingDescriptor::PiecewiseLinear(linear) => linear.y(x),
+		}
+	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(self.clone())
+	}
+}
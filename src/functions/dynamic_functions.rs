@@ -12,6 +12,11 @@ mod bezier {
 
 	/// User-defined cubic Bézier curve
 	#[derive(Copy, Clone, Debug)]
+	#[cfg_attr(
+		feature = "serde",
+		derive(serde::Serialize, serde::Deserialize),
+		serde(from = "BezierControlPoints", into = "BezierControlPoints")
+	)]
 	pub struct BezierCurve {
 		sample_table: [f32; SAMPLE_TABLE_SIZE],
 		p1: Vector2<f32>,
@@ -145,6 +150,28 @@ mod bezier {
 		}
 	}
 
+	impl BezierCurve {
+		/// The first control point of this curve
+		#[inline]
+		pub fn p1(&self) -> Vector2<f32> {
+			self.p1
+		}
+
+		/// The second control point of this curve
+		#[inline]
+		pub fn p2(&self) -> Vector2<f32> {
+			self.p2
+		}
+	}
+
+	impl PartialEq for BezierCurve {
+		/// Two curves are equal if they have the same control points
+		#[inline]
+		fn eq(&self, other: &Self) -> bool {
+			self.p1 == other.p1 && self.p2 == other.p2
+		}
+	}
+
 	impl EasingFunction for BezierCurve {
 		#[inline]
 		fn y(&self, x: f64) -> f64 {
@@ -154,6 +181,36 @@ mod bezier {
 				_ => BezierCurve::at(self.t_for_x(x as f32), self.p1.y, self.p2.y) as f64,
 			}
 		}
+
+		#[inline]
+		fn descriptor(&self) -> Option<EasingDescriptor> {
+			Some(EasingDescriptor::BezierCurve(*self))
+		}
+	}
+
+	// Only the control points are serialized, the sample table is calculated again when deserializing
+	#[cfg(feature = "serde")]
+	#[derive(serde::Serialize, serde::Deserialize)]
+	struct BezierControlPoints {
+		p1: [f32; 2],
+		p2: [f32; 2],
+	}
+
+	#[cfg(feature = "serde")]
+	impl From<BezierControlPoints> for BezierCurve {
+		fn from(points: BezierControlPoints) -> Self {
+			BezierCurve::from(Vector2::from(points.p1), Vector2::from(points.p2))
+		}
+	}
+
+	#[cfg(feature = "serde")]
+	impl From<BezierCurve> for BezierControlPoints {
+		fn from(curve: BezierCurve) -> Self {
+			BezierControlPoints {
+				p1: curve.p1.into(),
+				p2: curve.p2.into(),
+			}
+		}
 	}
 }
 
@@ -161,10 +218,28 @@ mod bezier {
 pub use bezier::*;
 
 /// User-defined easing function which wraps a normalized [`AnimationSequence<Float>`]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Keyframes([f64; SAMPLE_TABLE_SIZE]);
 
 impl Keyframes {
+	/// The evenly spaced samples of the normalized sequence that this function interpolates between
+	#[inline]
+	pub fn sample_table(&self) -> &[f64] {
+		&self.0
+	}
+
+	#[cfg(feature = "alloc")]
+	pub(crate) fn from_sample_table(samples: &[f64]) -> Option<Self> {
+		if samples.len() != SAMPLE_TABLE_SIZE {
+			return None;
+		}
+
+		let mut sample_table = [0.0; SAMPLE_TABLE_SIZE];
+		sample_table.copy_from_slice(samples);
+		Some(Keyframes(sample_table))
+	}
+
 	#[cfg(feature = "alloc")]
 	pub(crate) fn from_easing_function<T: Float + CanTween + Clone>(mut s: AnimationSequence<T>) -> Self {
 		let mut low_point = s.sequence.get(0).and_then(|kf| kf.value().to_f64()).unwrap_or(0.0);
@@ -212,4 +287,208 @@ impl EasingFunction for Keyframes {
 				+ (self.0[next_sample as usize] - self.0[current_sample as usize]) * difference
 		}
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::Keyframes(*self))
+	}
+}
+
+/// Where the jumps of a [`Steps`] function happen, same as the `<step-position>` of CSS `steps()`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StepPosition {
+	/// The first jump happens at the start, `jump-start` or `start` in CSS
+	JumpStart,
+	/// The last jump happens at the end, `jump-end` or `end` in CSS
+	JumpEnd,
+	/// There are no jumps at the start or end, `jump-none` in CSS
+	JumpNone,
+	/// There are jumps at both the start and the end, `jump-both` in CSS
+	JumpBoth,
+}
+
+impl StepPosition {
+	/// Creates a step position from whether there is a jump at the start and at the end
+	#[inline]
+	pub fn from_jumps(start: bool, end: bool) -> Self {
+		match (start, end) {
+			(true, false) => StepPosition::JumpStart,
+			(false, true) => StepPosition::JumpEnd,
+			(false, false) => StepPosition::JumpNone,
+			(true, true) => StepPosition::JumpBoth,
+		}
+	}
+
+	/// If there is a jump at the start
+	#[inline]
+	pub fn jumps_at_start(self) -> bool {
+		matches!(self, StepPosition::JumpStart | StepPosition::JumpBoth)
+	}
+
+	/// If there is a jump at the end
+	#[inline]
+	pub fn jumps_at_end(self) -> bool {
+		matches!(self, StepPosition::JumpEnd | StepPosition::JumpBoth)
+	}
+}
+
+/// Stepping function that divides the transition into equal intervals, mimics CSS `steps()` as defined [here](https://www.w3.org/TR/css-easing-1/#step-easing-functions)
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Steps {
+	steps: u32,
+	position: StepPosition,
+}
+
+impl Steps {
+	/// Creates a new stepping function.
+	///
+	/// # Arguments
+	///
+	/// * `steps` - The number of intervals, should be at least 1 (or 2 with `StepPosition::JumpNone`)
+	/// * `position` - Where the jumps happen
+	#[inline]
+	pub fn new(steps: u32, position: StepPosition) -> Self {
+		Steps { steps, position }
+	}
+
+	/// The number of intervals
+	#[inline]
+	pub fn steps(&self) -> u32 {
+		self.steps
+	}
+
+	/// Where the jumps happen
+	#[inline]
+	pub fn position(&self) -> StepPosition {
+		self.position
+	}
+}
+
+impl EasingFunction for Steps {
+	fn y(&self, x: f64) -> f64 {
+		let steps = self.steps as f64;
+		let jumps = match self.position {
+			StepPosition::JumpStart | StepPosition::JumpEnd => steps,
+			StepPosition::JumpNone => steps - 1.0,
+			StepPosition::JumpBoth => steps + 1.0,
+		}
+		.max(1.0); // Invalid step counts can't divide by zero
+
+		let mut current_step = (x * steps).floor();
+		if self.position.jumps_at_start() {
+			current_step += 1.0;
+		}
+
+		match current_step {
+			_ if x >= 0.0 && current_step < 0.0 => 0.0,
+			_ if x <= 1.0 && current_step > jumps => 1.0,
+			_ => current_step / jumps,
+		}
+	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::Steps(*self))
+	}
+}
+
+#[cfg(feature = "alloc")]
+mod piecewise_linear {
+	use crate::functions::dynamic_functions::*;
+	use alloc::{sync::Arc, vec::Vec};
+
+	/// Easing function that linearly interpolates between a list of points, mimics CSS `linear()` as defined [here](https://www.w3.org/TR/css-easing-2/#the-linear-easing-function)
+	///
+	/// The points are shared between clones, so cloning this function (e.g. to describe it) doesn't allocate.
+	#[derive(Clone, Debug, PartialEq)]
+	#[cfg_attr(
+		feature = "serde",
+		derive(serde::Serialize, serde::Deserialize),
+		serde(from = "PiecewiseLinearPoints", into = "PiecewiseLinearPoints")
+	)]
+	pub struct PiecewiseLinear {
+		points: Arc<[[f64; 2]]>,
+	}
+
+	impl PiecewiseLinear {
+		/// Creates a new piecewise linear function from a list of `[input, output]` points.
+		/// Like in CSS, an input that is smaller than the input of a previous point is raised to that input.
+		///
+		/// With no points this function is the same as `Linear`, with one point the output is always the same.
+		/// Inputs before the first point or after the last point are extrapolated from the first or last two points.
+		pub fn new(mut points: Vec<[f64; 2]>) -> Self {
+			let mut largest_input = f64::NEG_INFINITY;
+			for point in points.iter_mut() {
+				largest_input = largest_input.max(point[0]);
+				point[0] = largest_input;
+			}
+
+			PiecewiseLinear { points: points.into() }
+		}
+
+		/// The `[input, output]` points of this function
+		#[inline]
+		pub fn points(&self) -> &[[f64; 2]] {
+			&self.points
+		}
+	}
+
+	impl EasingFunction for PiecewiseLinear {
+		fn y(&self, x: f64) -> f64 {
+			let points = &self.points;
+
+			match points.len() {
+				0 => x,
+				1 => points[0][1],
+				length => {
+					// The segment containing x, or the first/last segment if x is outside of the points
+					let i = points
+						.iter()
+						.rposition(|point| point[0] <= x)
+						.unwrap_or(0)
+						.min(length - 2);
+					let ([x1, y1], [x2, y2]) = (points[i], points[i + 1]);
+
+					if x1 == x2 {
+						y2
+					} else {
+						y1 + (y2 - y1) * (x - x1) / (x2 - x1)
+					}
+				}
+			}
+		}
+
+		#[inline]
+		fn descriptor(&self) -> Option<EasingDescriptor> {
+			Some(EasingDescriptor::PiecewiseLinear(self.clone()))
+		}
+	}
+
+	// Serialized like a struct with a list of points, the points are normalized again when deserializing
+	#[cfg(feature = "serde")]
+	#[derive(serde::Serialize, serde::Deserialize)]
+	struct PiecewiseLinearPoints {
+		points: Vec<[f64; 2]>,
+	}
+
+	#[cfg(feature = "serde")]
+	impl From<PiecewiseLinearPoints> for PiecewiseLinear {
+		fn from(points: PiecewiseLinearPoints) -> Self {
+			PiecewiseLinear::new(points.points)
+		}
+	}
+
+	#[cfg(feature = "serde")]
+	impl From<PiecewiseLinear> for PiecewiseLinearPoints {
+		fn from(function: PiecewiseLinear) -> Self {
+			PiecewiseLinearPoints {
+				points: function.points.to_vec(),
+			}
+		}
+	}
 }
+
+#[cfg(feature = "alloc")]
+pub use piecewise_linear::*;
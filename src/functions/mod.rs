@@ -3,3 +3,6 @@ pub use static_functions::*;
 
 mod dynamic_functions;
 pub use dynamic_functions::*;
+
+mod descriptor;
+pub use descriptor::*;
@@ -13,6 +13,11 @@ impl EasingFunction for Linear {
 	fn y(&self, x: f64) -> f64 {
 		x
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::Linear)
+	}
 }
 
 /// Step function, returns the closest to either point A or B
@@ -25,6 +30,11 @@ impl EasingFunction for Step {
 	fn y(&self, x: f64) -> f64 {
 		x.round()
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::Step)
+	}
 }
 
 /// Hold function, always returns A
@@ -37,6 +47,11 @@ impl EasingFunction for Hold {
 	fn y(&self, _x: f64) -> f64 {
 		0.0
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::Hold)
+	}
 }
 
 /// Accelerating quadratically from point A to point B
@@ -49,6 +64,11 @@ impl EasingFunction for EaseInQuad {
 	fn y(&self, x: f64) -> f64 {
 		x * x
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseInQuad)
+	}
 }
 
 /// Decelerating quadratically from point A to point B
@@ -61,6 +81,11 @@ impl EasingFunction for EaseOutQuad {
 	fn y(&self, x: f64) -> f64 {
 		x * (2.0 - x)
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseOutQuad)
+	}
 }
 
 /// Accelerating then decelerating quadratically from point A to point B
@@ -77,6 +102,11 @@ impl EasingFunction for EaseInOutQuad {
 			-1.0 + (4.0 - 2.0 * x) * x
 		}
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseInOutQuad)
+	}
 }
 
 /// Accelerating cubically from point A to point B
@@ -89,6 +119,11 @@ impl EasingFunction for EaseInCubic {
 	fn y(&self, x: f64) -> f64 {
 		x * x * x
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseInCubic)
+	}
 }
 
 /// Decelerating cubically from point A to point B
@@ -102,6 +137,11 @@ impl EasingFunction for EaseOutCubic {
 		let x_minus_one = x - 1.0;
 		1.0 + x_minus_one * x_minus_one * x_minus_one
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseOutCubic)
+	}
 }
 
 /// Accelerating then decelerating cubically from point A to point B
@@ -119,6 +159,11 @@ impl EasingFunction for EaseInOutCubic {
 			x_minus_one * (2.0 * x - 2.0) * (2.0 * x - 2.0) + 1.0
 		}
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseInOutCubic)
+	}
 }
 
 /// Accelerating quartically from point A to point B
@@ -131,6 +176,11 @@ impl EasingFunction for EaseInQuart {
 	fn y(&self, x: f64) -> f64 {
 		x * x * x * x
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseInQuart)
+	}
 }
 
 /// Decelerating quartically from point A to point B
@@ -144,6 +194,11 @@ impl EasingFunction for EaseOutQuart {
 		let x_minus_one = x - 1.0;
 		1.0 - x_minus_one * x_minus_one * x_minus_one * x_minus_one
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseOutQuart)
+	}
 }
 
 /// Accelerating then decelerating quartically from point A to point B
@@ -161,6 +216,11 @@ impl EasingFunction for EaseInOutQuart {
 			1.0 - 8.0 * x_minus_one * x_minus_one * x_minus_one * x_minus_one
 		}
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseInOutQuart)
+	}
 }
 
 /// Accelerating quintically from point A to point B
@@ -173,6 +233,11 @@ impl EasingFunction for EaseInQuint {
 	fn y(&self, x: f64) -> f64 {
 		x * x * x * x * x
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseInQuint)
+	}
 }
 
 /// Decelerating quintically from point A to point B
@@ -186,6 +251,11 @@ impl EasingFunction for EaseOutQuint {
 		let x_minus_one = x - 1.0;
 		1.0 + x_minus_one * x_minus_one * x_minus_one * x_minus_one * x_minus_one
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseOutQuint)
+	}
 }
 
 /// Accelerating then decelerating quintically from point A to point B
@@ -203,6 +273,11 @@ impl EasingFunction for EaseInOutQuint {
 			1.0 + 16.0 * x_minus_one * x_minus_one * x_minus_one * x_minus_one * x_minus_one
 		}
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseInOutQuint)
+	}
 }
 
 /// Accelerating on 1/4 of a sine wave from point A to point B
@@ -215,6 +290,11 @@ impl EasingFunction for EaseIn {
 	fn y(&self, x: f64) -> f64 {
 		((x - 1.0) * core::f64::consts::FRAC_PI_2).sin() + 1.0
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseIn)
+	}
 }
 
 /// Decelerating on 1/4 of a sine wave from point A to point B
@@ -227,6 +307,11 @@ impl EasingFunction for EaseOut {
 	fn y(&self, x: f64) -> f64 {
 		(x * core::f64::consts::FRAC_PI_2).sin()
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseOut)
+	}
 }
 
 /// Accelerating then decelerating on 1/2 of a sine wave from point A to point B
@@ -239,4 +324,9 @@ impl EasingFunction for EaseInOut {
 	fn y(&self, x: f64) -> f64 {
 		0.5 * (1.0 - (x * core::f64::consts::PI).cos())
 	}
+
+	#[inline]
+	fn descriptor(&self) -> Option<EasingDescriptor> {
+		Some(EasingDescriptor::EaseInOut)
+	}
 }
@@ -0,0 +1,71 @@
+use glam::{
+	Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2, Mat3, Mat3A, Mat4,
+	Quat, Vec2, Vec3, Vec3A, Vec4,
+};
+
+use crate::{as_f64, as_t, rotation, transform, CanTween, Float};
+
+// Vectors are interpolated with glam's own lerp, which uses SIMD for Vec3A and Vec4 where available
+macro_rules! impl_can_tween_for_vector {
+	($scalar: ty, $($vector: ty),+) => {
+		$(
+			impl CanTween for $vector {
+				#[inline]
+				fn ease(from: Self, to: Self, time: impl Float) -> Self {
+					from.lerp(to, as_t::<$scalar>(as_f64(time)))
+				}
+			}
+		)+
+	};
+}
+
+impl_can_tween_for_vector!(f32, Vec2, Vec3, Vec3A, Vec4);
+impl_can_tween_for_vector!(f64, DVec2, DVec3, DVec4);
+
+macro_rules! impl_can_tween_for_quaternion {
+	($($quaternion: ty),+) => {
+		$(
+			impl CanTween for $quaternion {
+				/// Spherical linear interpolation (slerp) between two rotations.
+				/// The shortest path is always taken and the returned quaternion is normalized.
+				#[inline]
+				fn ease(from: Self, to: Self, time: impl Float) -> Self {
+					let convert = |q: Self| {
+						let [x, y, z, w] = q.to_array();
+						[as_f64(x), as_f64(y), as_f64(z), as_f64(w)]
+					};
+					let [x, y, z, w] = rotation::slerp(convert(from), convert(to), as_f64(time));
+
+					Self::from_array([as_t(x), as_t(y), as_t(z), as_t(w)])
+				}
+			}
+		)+
+	};
+}
+
+impl_can_tween_for_quaternion!(Quat, DQuat);
+
+// Matrices are decomposed into translation, rotation, scale and skew which are interpolated separately,
+// see the implementations for mint matrices.
+macro_rules! impl_can_tween_for_matrix {
+	($function: ident, $($matrix: ty),+) => {
+		$(
+			impl CanTween for $matrix {
+				#[inline]
+				fn ease(from: Self, to: Self, time: impl Float) -> Self {
+					Self::from_cols_array_2d(&transform::$function(
+						from.to_cols_array_2d(),
+						to.to_cols_array_2d(),
+						time,
+					))
+				}
+			}
+		)+
+	};
+}
+
+impl_can_tween_for_matrix!(ease_linear_2d, Mat2, DMat2);
+impl_can_tween_for_matrix!(ease_linear_3d, Mat3, Mat3A, DMat3);
+impl_can_tween_for_matrix!(ease_projective_3d, Mat4, DMat4);
+impl_can_tween_for_matrix!(ease_affine_2d, Affine2, DAffine2);
+impl_can_tween_for_matrix!(ease_affine_3d, Affine3A, DAffine3);
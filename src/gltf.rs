@@ -0,0 +1,339 @@
+//! Import of [glTF 2.0](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#animations) animation samplers.
+//!
+//! [`load_sampler`] turns the data of a sampler into an animation sequence for the path of the channel that uses it.
+//! The data is passed as slices of floats, so any glTF crate (or none) can be used to read the file and its buffers.
+//! Accessors with normalized integers (e.g. rotations stored as `i16`) have to be converted to floats first.
+//!
+//! `LINEAR` keyframes are tweened linearly (rotations with slerp) and `STEP` keyframes are held until the next keyframe.
+//! `CUBICSPLINE` segments whose tangents point along the segment are represented exactly with a [`BezierCurve`],
+//! other segments (including all rotations) are sampled into several keyframes.
+//!
+//! ```rust
+//! use keyframe::gltf::{load_sampler, GltfChannel, GltfInterpolation, GltfPath};
+//!
+//! let times = [0.0, 1.0];
+//! let rotations = [0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0];
+//!
+//! match load_sampler(GltfPath::Rotation, &times, &rotations, GltfInterpolation::Linear).unwrap() {
+//!     GltfChannel::Rotation(mut sequence) => {
+//!         sequence.advance_to(0.5);
+//!         let rotation = sequence.now_strict().unwrap();
+//!         assert!((rotation.v.y - 0.7071).abs() < 0.001);
+//!     }
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+	functions::{BezierCurve, Hold, Linear},
+	AnimationSequence, CanTween, Float, Keyframe, Quaternion, Vector2, Vector3,
+};
+
+// Number of keyframes a cubic spline segment is split into if it can't be represented with a Bézier curve
+const CUBIC_SPLINE_SAMPLES: usize = 8;
+// Relative difference allowed between tangents and the direction of a segment for it to be represented with a Bézier curve
+const TANGENT_TOLERANCE: f32 = 1e-5;
+
+/// Category of glTF import error
+#[derive(Debug)]
+pub enum GltfError {
+	/// The sampler doesn't have any keyframes
+	EmptyInput,
+	/// The number of output values doesn't match the number of keyframes
+	OutputLength {
+		/// Number of floats expected for the number of keyframes
+		expected: usize,
+		/// Number of floats in the output
+		found: usize,
+	},
+	/// The time of this keyframe is not finite or not larger than the time of the previous keyframe
+	InvalidTime(usize),
+}
+
+impl fmt::Display for GltfError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			GltfError::EmptyInput => f.write_str("the sampler doesn't have any keyframes"),
+			GltfError::OutputLength { expected, found } => {
+				write!(f, "expected {} output values but found {}", expected, found)
+			}
+			GltfError::InvalidTime(index) => write!(f, "the time of keyframe {} is not valid", index),
+		}
+	}
+}
+
+/// Interpolation of a sampler (`sampler.interpolation`)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GltfInterpolation {
+	/// `LINEAR`
+	Linear,
+	/// `STEP`
+	Step,
+	/// `CUBICSPLINE`, every keyframe has an in-tangent, a value and an out-tangent
+	CubicSpline,
+}
+
+impl GltfInterpolation {
+	/// The interpolation with this name in a glTF file, e.g. `"CUBICSPLINE"`
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"LINEAR" => Some(GltfInterpolation::Linear),
+			"STEP" => Some(GltfInterpolation::Step),
+			"CUBICSPLINE" => Some(GltfInterpolation::CubicSpline),
+			_ => None,
+		}
+	}
+}
+
+/// Property that a channel animates (`channel.target.path`)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GltfPath {
+	/// `translation`, three floats per value
+	Translation,
+	/// `rotation`, a quaternion in X, Y, Z, W order per value
+	Rotation,
+	/// `scale`, three floats per value
+	Scale,
+	/// `weights`, one float per morph target per value
+	Weights,
+}
+
+impl GltfPath {
+	/// The path with this name in a glTF file, e.g. `"rotation"`
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"translation" => Some(GltfPath::Translation),
+			"rotation" => Some(GltfPath::Rotation),
+			"scale" => Some(GltfPath::Scale),
+			"weights" => Some(GltfPath::Weights),
+			_ => None,
+		}
+	}
+}
+
+/// Animation of a node property
+#[derive(Clone, Debug)]
+pub enum GltfChannel {
+	/// Translation of the node
+	Translation(AnimationSequence<Vector3<f32>>),
+	/// Rotation of the node
+	Rotation(AnimationSequence<Quaternion<f32>>),
+	/// Scale of the node
+	Scale(AnimationSequence<Vector3<f32>>),
+	/// Weights of the morph targets of the node's mesh
+	Weights(AnimationSequence<Vec<f32>>),
+}
+
+/// Creates the animation of a channel from the data of its sampler.
+///
+/// # Arguments
+///
+/// * `path` - The property that the channel animates
+/// * `input` - The times of the keyframes in seconds (the `input` accessor)
+/// * `output` - The values of the keyframes (the `output` accessor) as floats.
+///   For `CUBICSPLINE` every keyframe has an in-tangent, a value and an out-tangent.
+/// * `interpolation` - How values are interpolated between keyframes
+pub fn load_sampler(
+	path: GltfPath,
+	input: &[f32],
+	output: &[f32],
+	interpolation: GltfInterpolation,
+) -> Result<GltfChannel, GltfError> {
+	let vector = |c: &[f32]| Vector3 {
+		x: c[0],
+		y: c[1],
+		z: c[2],
+	};
+
+	match path {
+		GltfPath::Translation => sequence(input, output, interpolation, 3, vector, true).map(GltfChannel::Translation),
+		GltfPath::Scale => sequence(input, output, interpolation, 3, vector, true).map(GltfChannel::Scale),
+		GltfPath::Rotation => sequence(input, output, interpolation, 4, quaternion, false).map(GltfChannel::Rotation),
+		GltfPath::Weights => {
+			let values = if interpolation == GltfInterpolation::CubicSpline {
+				input.len() * 3
+			} else {
+				input.len()
+			};
+			// The number of morph targets is only known from the length of the output
+			let targets = output.len().checked_div(values).unwrap_or(0);
+
+			sequence(input, output, interpolation, targets.max(1), <[f32]>::to_vec, true).map(GltfChannel::Weights)
+		}
+	}
+}
+
+/// Normalized quaternion from X, Y, Z and W, cubic spline interpolation doesn't keep quaternions normalized
+fn quaternion(c: &[f32]) -> Quaternion<f32> {
+	let length = Float::sqrt(c[0] * c[0] + c[1] * c[1] + c[2] * c[2] + c[3] * c[3]);
+	let scale = if length > 0.0 { 1.0 / length } else { 1.0 };
+
+	Quaternion {
+		v: Vector3 {
+			x: c[0] * scale,
+			y: c[1] * scale,
+			z: c[2] * scale,
+		},
+		s: c[3] * scale,
+	}
+}
+
+/// Creates a sequence from values with `components` floats each.
+/// `componentwise` is true if `T` is tweened linearly per component, only then can a cubic spline segment be a Bézier curve.
+fn sequence<T: CanTween + Clone>(
+	input: &[f32],
+	output: &[f32],
+	interpolation: GltfInterpolation,
+	components: usize,
+	value: impl Fn(&[f32]) -> T,
+	componentwise: bool,
+) -> Result<AnimationSequence<T>, GltfError> {
+	if input.is_empty() {
+		return Err(GltfError::EmptyInput);
+	}
+
+	let stride = if interpolation == GltfInterpolation::CubicSpline {
+		components * 3
+	} else {
+		components
+	};
+	if output.len() != input.len() * stride {
+		return Err(GltfError::OutputLength {
+			expected: input.len() * stride,
+			found: output.len(),
+		});
+	}
+
+	for (i, &time) in input.iter().enumerate() {
+		if !time.is_finite() || (i > 0 && time <= input[i - 1]) {
+			return Err(GltfError::InvalidTime(i));
+		}
+	}
+
+	let values = output.chunks_exact(stride);
+	let keyframes = match interpolation {
+		GltfInterpolation::Linear => input
+			.iter()
+			.zip(values)
+			.map(|(&time, v)| Keyframe::new(value(v), time, Linear))
+			.collect(),
+		GltfInterpolation::Step => input
+			.iter()
+			.zip(values)
+			.map(|(&time, v)| Keyframe::new(value(v), time, Hold))
+			.collect(),
+		GltfInterpolation::CubicSpline => {
+			let mut keyframes = Vec::with_capacity(input.len());
+
+			for i in 0..input.len() {
+				// In-tangent, value and out-tangent
+				let at = |i: usize, part: usize| &output[i * stride + part * components..][..components];
+				let (time, start) = (input[i], at(i, 1));
+
+				if i + 1 == input.len() {
+					keyframes.push(Keyframe::new(value(start), time, Linear));
+					continue;
+				}
+
+				let segment = CubicSegment {
+					duration: input[i + 1] - time,
+					start,
+					out_tangent: at(i, 2),
+					in_tangent: at(i + 1, 0),
+					end: at(i + 1, 1),
+				};
+
+				match segment.bezier_curve().filter(|_| componentwise) {
+					Some(curve) => keyframes.push(Keyframe::new(value(start), time, curve)),
+					None => {
+						let mut sample = Vec::with_capacity(components);
+						for j in 0..CUBIC_SPLINE_SAMPLES {
+							let s = j as f32 / CUBIC_SPLINE_SAMPLES as f32;
+							segment.at(s, &mut sample);
+							keyframes.push(Keyframe::new(value(&sample), time + segment.duration * s, Linear));
+						}
+					}
+				}
+			}
+
+			keyframes
+		}
+	};
+
+	Ok(AnimationSequence::from(keyframes))
+}
+
+/// Cubic Hermite spline between two keyframes
+struct CubicSegment<'a> {
+	duration: f32,
+	start: &'a [f32],
+	out_tangent: &'a [f32],
+	in_tangent: &'a [f32],
+	end: &'a [f32],
+}
+
+impl CubicSegment<'_> {
+	/// The value at `s` (0.0 to 1.0) as defined [here](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#interpolation-cubic)
+	fn at(&self, s: f32, out: &mut Vec<f32>) {
+		let (s2, s3) = (s * s, s * s * s);
+		let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+		let h10 = s3 - 2.0 * s2 + s;
+		let h01 = -2.0 * s3 + 3.0 * s2;
+		let h11 = s3 - s2;
+
+		out.clear();
+		out.extend((0..self.start.len()).map(|i| {
+			h00 * self.start[i]
+				+ h10 * self.duration * self.out_tangent[i]
+				+ h01 * self.end[i]
+				+ h11 * self.duration * self.in_tangent[i]
+		}));
+	}
+
+	/// If both tangents point along the segment the spline is `start + (end - start) * y(s)` where `y` is a cubic polynomial,
+	/// which is a Bézier curve with the control points at one and two thirds of the X axis
+	fn bezier_curve(&self) -> Option<BezierCurve> {
+		let delta: Vec<f32> = self
+			.start
+			.iter()
+			.zip(self.end)
+			.map(|(start, end)| end - start)
+			.collect();
+		let largest = delta.iter().fold(0.0f32, |largest, d| largest.max(d.abs()));
+
+		// The slope of the easing function at both ends
+		let slope = |tangent: &[f32]| -> Option<f32> {
+			let i = delta.iter().position(|d| d.abs() == largest)?;
+			let slope = if largest > 0.0 {
+				self.duration * tangent[i] / delta[i]
+			} else {
+				0.0
+			};
+
+			let along_segment = tangent.iter().zip(&delta).all(|(&tangent, &d)| {
+				let difference = self.duration * tangent - slope * d;
+				difference.abs() <= TANGENT_TOLERANCE * largest.max(1.0)
+			});
+			if along_segment {
+				Some(slope)
+			} else {
+				None
+			}
+		};
+
+		let (start_slope, end_slope) = (slope(self.out_tangent)?, slope(self.in_tangent)?);
+		Some(BezierCurve::from(
+			Vector2 {
+				x: 1.0 / 3.0,
+				y: start_slope / 3.0,
+			},
+			Vector2 {
+				x: 2.0 / 3.0,
+				y: 1.0 - end_slope / 3.0,
+			},
+		))
+	}
+}
@@ -0,0 +1,119 @@
+use core::marker::PhantomData;
+use num_traits::PrimInt;
+
+use crate::{as_f64, CanTween, Float};
+
+/// Rounding mode for tweening integers
+pub trait Rounding {
+	/// Rounds the interpolated value to a whole number
+	fn round(value: f64) -> f64;
+}
+
+/// Rounds to the nearest integer, and half-way cases away from zero
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nearest;
+impl Rounding for Nearest {
+	#[inline]
+	fn round(value: f64) -> f64 {
+		value.round()
+	}
+}
+
+/// Rounds down to the largest integer less than or equal to the value
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Floor;
+impl Rounding for Floor {
+	#[inline]
+	fn round(value: f64) -> f64 {
+		value.floor()
+	}
+}
+
+/// Rounds up to the smallest integer greater than or equal to the value
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ceil;
+impl Rounding for Ceil {
+	#[inline]
+	fn round(value: f64) -> f64 {
+		value.ceil()
+	}
+}
+
+/// Rounds towards zero, removing the fractional part of the value
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Truncate;
+impl Rounding for Truncate {
+	#[inline]
+	fn round(value: f64) -> f64 {
+		value.trunc()
+	}
+}
+
+/// Interpolates two integers through f64, the result saturates at the bounds of the integer type
+fn ease_integer<I: PrimInt, R: Rounding>(from: I, to: I, time: impl Float) -> I {
+	let as_float = |i: I| i.to_f64().expect("Integer not representable in f64");
+	let (min, max) = (I::min_value(), I::max_value());
+
+	match R::round(as_float(from) + (as_float(to) - as_float(from)) * as_f64(time)) {
+		value if value.is_nan() => from,
+		value if value >= as_float(max) => max,
+		value if value <= as_float(min) => min,
+		value => I::from(value).unwrap_or(from),
+	}
+}
+
+macro_rules! impl_can_tween_for_integer {
+	($($integer: ty),*) => {
+		$(
+			impl CanTween for $integer {
+				/// Interpolates through f64, rounds to the nearest integer and saturates at the bounds of the integer type.
+				/// Use [`Rounded`] to choose a different rounding mode.
+				#[inline]
+				fn ease(from: Self, to: Self, time: impl Float) -> Self {
+					ease_integer::<Self, Nearest>(from, to, time)
+				}
+			}
+		)*
+	};
+}
+
+impl_can_tween_for_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Integer that is rounded with `R` when tweened
+///
+/// ```rust
+/// use keyframe::{ease, functions::Linear, Floor, Rounded};
+///
+/// assert_eq!(ease(Linear, Rounded::<u8, Floor>::new(0), Rounded::new(10), 0.99).value, 9);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rounded<T, R = Nearest> {
+	/// The integer value
+	pub value: T,
+	rounding: PhantomData<R>,
+}
+
+impl<T, R> Rounded<T, R> {
+	/// Creates a new integer with a rounding mode
+	#[inline]
+	pub fn new(value: T) -> Self {
+		Rounded {
+			value,
+			rounding: PhantomData,
+		}
+	}
+}
+
+impl<T, R> From<T> for Rounded<T, R> {
+	#[inline]
+	fn from(value: T) -> Self {
+		Rounded::new(value)
+	}
+}
+
+impl<T: PrimInt, R: Rounding> CanTween for Rounded<T, R> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Rounded::new(ease_integer::<T, R>(from.value, to.value, time))
+	}
+}
@@ -7,10 +7,13 @@ use crate::{
 	CanTween, EasingFunction,
 };
 
+#[cfg(feature = "serde")]
+use crate::functions::EasingDescriptor;
+
 /// Intermediate step in an animation sequence
 #[derive(Clone)]
 pub struct Keyframe<T> {
-	value: T,
+	pub(crate) value: T,
 	pub(crate) time: f64,
 	function: Arc<dyn EasingFunction + Send + Sync>,
 }
@@ -102,6 +105,31 @@ impl<T> Keyframe<T> {
 			),
 		}
 	}
+
+	/// Same as [`tween_to`](#method.tween_to), but writes the value into `out` instead of cloning both keyframe values
+	pub fn tween_into(&self, next: &Keyframe<T>, time: impl Float, out: &mut T)
+	where
+		T: CanTween + Clone,
+	{
+		match as_f64(time) {
+			time if time < self.time => out.clone_from(&self.value),
+			time if time > next.time => out.clone_from(&next.value),
+			_ if next.time < self.time => out.clone_from(&next.value),
+
+			time => T::ease_into(
+				&self.value,
+				&next.value,
+				self.function.y(ease_with_scaled_time(
+					Linear,
+					0.0,
+					1.0,
+					time - self.time,
+					next.time - self.time,
+				)),
+				out,
+			),
+		}
+	}
 }
 
 impl<V, T: Float> From<(V, T)> for Keyframe<V> {
@@ -132,6 +160,30 @@ impl<V, T: Float> From<(V, T, Box<dyn EasingFunction + 'static + Send + Sync>)>
 	}
 }
 
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Keyframe<T> {
+	/// Serializes this keyframe as a tuple of (value, time, function).
+	/// Fails if the easing function is user-defined and doesn't have a [`EasingDescriptor`].
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		use serde::ser::Error;
+
+		let function = self
+			.function
+			.descriptor()
+			.ok_or_else(|| S::Error::custom("user-defined easing functions can't be serialized"))?;
+		(&self.value, self.time, function).serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Keyframe<T> {
+	/// Deserializes a keyframe from a tuple of (value, time, function)
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let (value, time, function) = <(T, f64, EasingDescriptor)>::deserialize(deserializer)?;
+		Ok(Keyframe::new(value, time, function))
+	}
+}
+
 impl<T: fmt::Display> fmt::Display for Keyframe<T> {
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
@@ -142,6 +194,28 @@ impl<T: fmt::Display> fmt::Display for Keyframe<T> {
 impl<T: core::fmt::Debug> fmt::Debug for Keyframe<T> {
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-		write!(f, "Keyframe {{ value: {:?}, time: {:.2} }}", self.value, self.time)
+		write!(f, "Keyframe {{ value: {:?}, time: {:.2}, function: ", self.value, self.time)?;
+		match self.function.descriptor() {
+			Some(descriptor) => write!(f, "{}", descriptor)?,
+			None => f.write_str("<user-defined>")?,
+		}
+		f.write_str(" }")
+	}
+}
+
+impl<T: PartialEq> PartialEq for Keyframe<T> {
+	/// Keyframes are equal if they have the same value, time and easing function.
+	/// User-defined easing functions without a descriptor are only equal to themselves.
+	fn eq(&self, other: &Self) -> bool {
+		// Descriptors are only built if the keyframes don't share their function
+		let same_function = || {
+			Arc::ptr_eq(&self.function, &other.function)
+				|| match (self.function.descriptor(), other.function.descriptor()) {
+					(Some(a), Some(b)) => a == b,
+					_ => false,
+				}
+		};
+
+		self.value == other.value && self.time == other.time && same_function()
 	}
 }
@@ -61,7 +61,7 @@
 //! Custom structures:
 //!
 //! ```rust
-//! use keyframe::mint::Point2;
+//! use keyframe::{ease, functions::Linear, mint::Point2};
 //! // This macro works with any structure as long as it only consists of types that implement "CanTween"
 //! use keyframe_derive::CanTween;
 //!
@@ -75,21 +75,61 @@
 //!     a: f64,
 //!     b: Point2<f64>,
 //!     c: f32,
-//!     d: [MySubStructure; N] // Array length matching is guaranteed by the type system
+//!     d: [MySubStructure; 2], // Array length matching is guaranteed by the type system
+//!     #[tween(skip)] // Fields can also be switched instead of tweened, see the documentation of keyframe_derive
+//!     e: u32
 //! }
 //!
 //! // Also works with unnamed structures
 //! #[derive(CanTween)]
 //! struct UnnamedStructure(MyStructure, f64);
+//!
+//! // And with enums, values with different variants switch half-way through
+//! #[derive(CanTween, Debug, PartialEq)]
+//! enum MyEnum {
+//!     A(f64),
+//!     B { b: Point2<f64> },
+//!     C
+//! }
+//!
+//! let from = MyStructure {
+//!     a: 0.0,
+//!     b: Point2 { x: 0.0, y: 0.0 },
+//!     c: 0.0,
+//!     d: [MySubStructure { a: 0.0 }, MySubStructure { a: 1.0 }],
+//!     e: 1
+//! };
+//! let to = MyStructure {
+//!     a: 1.0,
+//!     b: Point2 { x: 2.0, y: 4.0 },
+//!     c: 1.0,
+//!     d: [MySubStructure { a: 1.0 }, MySubStructure { a: 3.0 }],
+//!     e: 2
+//! };
+//!
+//! let value = ease(Linear, from, to, 0.5);
+//! assert_eq!(value.b, Point2 { x: 1.0, y: 2.0 });
+//! assert_eq!(value.d[1].a, 2.0);
+//! assert_eq!(value.e, 1); // Skipped fields keep the value of `from`
+//!
+//! assert_eq!(ease(Linear, MyEnum::A(0.0), MyEnum::A(1.0), 0.5), MyEnum::A(0.5));
+//! assert_eq!(ease(Linear, MyEnum::A(0.0), MyEnum::C, 0.25), MyEnum::A(0.0));
+//! assert_eq!(ease(Linear, MyEnum::A(0.0), MyEnum::C, 0.75), MyEnum::C);
 //! ```
 #![no_std]
 
+#[cfg(feature = "cgmath")]
+pub use ::cgmath;
+#[cfg(feature = "glam")]
+pub use ::glam;
+#[cfg(feature = "nalgebra")]
+pub use ::nalgebra;
 #[cfg(feature = "mint_types")]
 pub use mint;
 pub use num_traits;
 
 #[cfg(feature = "mint_types")]
-pub(crate) use mint::{Point2, Point3, Vector2, Vector3, Vector4};
+pub(crate) use mint::{EulerAngles, Point2, Point3, Quaternion, Vector2, Vector3, Vector4};
 pub(crate) use num_traits::Float;
 
 #[cfg(feature = "alloc")]
@@ -121,6 +161,140 @@ use functions::*;
 mod easing;
 pub use easing::*;
 
+mod angle;
+pub use angle::*;
+
+mod integer;
+pub use integer::*;
+
+mod discrete;
+pub use discrete::*;
+
+// Used by code generated with keyframe_derive
+#[doc(hidden)]
+pub mod __private {
+	use core::marker::PhantomData;
+
+	use crate::{CanTween, Float};
+
+	#[inline]
+	pub fn switch<T>(from: T, to: T, time: impl Float, threshold: f64) -> T {
+		crate::discrete::switch(from, to, time, threshold)
+	}
+
+	// Tweens a field in place if its type implements Clone, `(&Field::<T>(PhantomData)).ease_into(...)` finds the methods
+	// of `InPlace` before those of `Fallback` because `InPlace` is implemented for the reference
+	pub struct Field<T>(pub PhantomData<T>);
+
+	impl<T> Clone for Field<T> {
+		#[inline]
+		fn clone(&self) -> Self {
+			*self
+		}
+	}
+
+	impl<T> Copy for Field<T> {}
+
+	// Each method returns if the field has been tweened
+	pub trait InPlace<T> {
+		fn ease_into(self, from: &T, to: &T, time: impl Float, out: &mut T) -> bool
+		where
+			T: CanTween;
+		fn map_into(self, from: &T, to: &T, out: &mut T, function: impl FnOnce(T, T) -> T) -> bool;
+		fn switch_into(self, from: &T, to: &T, time: impl Float, threshold: f64, out: &mut T) -> bool;
+	}
+
+	impl<T: Clone> InPlace<T> for &Field<T> {
+		#[inline]
+		fn ease_into(self, from: &T, to: &T, time: impl Float, out: &mut T) -> bool
+		where
+			T: CanTween,
+		{
+			T::ease_into(from, to, time, out);
+			true
+		}
+
+		#[inline]
+		fn map_into(self, from: &T, to: &T, out: &mut T, function: impl FnOnce(T, T) -> T) -> bool {
+			*out = function(from.clone(), to.clone());
+			true
+		}
+
+		#[inline]
+		fn switch_into(self, from: &T, to: &T, time: impl Float, threshold: f64, out: &mut T) -> bool {
+			out.clone_from(crate::discrete::switch(from, to, time, threshold));
+			true
+		}
+	}
+
+	pub trait Fallback<T> {
+		fn ease_into(self, from: &T, to: &T, time: impl Float, out: &mut T) -> bool
+		where
+			T: CanTween;
+		fn map_into(self, from: &T, to: &T, out: &mut T, function: impl FnOnce(T, T) -> T) -> bool;
+		fn switch_into(self, from: &T, to: &T, time: impl Float, threshold: f64, out: &mut T) -> bool;
+	}
+
+	impl<T> Fallback<T> for Field<T> {
+		#[inline]
+		fn ease_into(self, _: &T, _: &T, _: impl Float, _: &mut T) -> bool
+		where
+			T: CanTween,
+		{
+			false
+		}
+
+		#[inline]
+		fn map_into(self, _: &T, _: &T, _: &mut T, _: impl FnOnce(T, T) -> T) -> bool {
+			false
+		}
+
+		#[inline]
+		fn switch_into(self, _: &T, _: &T, _: impl Float, _: f64, _: &mut T) -> bool {
+			false
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+mod collections;
+#[cfg(feature = "alloc")]
+pub use collections::*;
+
+pub mod color;
+
+#[cfg(any(feature = "mint_types", feature = "glam", feature = "nalgebra", feature = "cgmath"))]
+mod rotation;
+#[cfg(any(feature = "mint_types", feature = "glam", feature = "cgmath"))]
+mod transform;
+
+#[cfg(feature = "cgmath")]
+mod cgmath_type_impls;
+#[cfg(feature = "glam")]
+mod glam_type_impls;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_type_impls;
+
+#[cfg(feature = "css")]
+pub mod css;
+
+#[cfg(feature = "gltf")]
+pub mod gltf;
+#[cfg(feature = "lottie")]
+pub mod lottie;
+
+#[cfg(feature = "alloc")]
+pub mod csv;
+#[cfg(feature = "svg")]
+pub mod svg;
+#[cfg(feature = "alloc")]
+pub mod text;
+
+#[cfg(feature = "alloc")]
+mod registry;
+#[cfg(feature = "alloc")]
+pub use registry::*;
+
 #[cfg(feature = "alloc")]
 mod keyframe;
 #[cfg(feature = "alloc")]
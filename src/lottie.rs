@@ -0,0 +1,589 @@
+//! Import of animated properties from [Lottie](https://lottiefiles.github.io/lottie-docs/) (Bodymovin JSON) files.
+//!
+//! [`parse_lottie`] reads the transform and opacity of every layer into animation sequences,
+//! [`parse_property`] reads a single animated property. Times are converted from frames to seconds.
+//!
+//! Keyframes use their `i`/`o` easing handles as a [`BezierCurve`], hold keyframes (`h`) use [`Hold`].
+//! Segments that follow a curved path because of spatial tangents (`ti`/`to`) are sampled into several keyframes
+//! that move along the path, since animation sequences only tween in straight lines.
+//! Expressions, time remapping and layer parenting are not evaluated.
+//!
+//! ```rust
+//! use keyframe::lottie::parse_lottie;
+//!
+//! let animation = parse_lottie(r#"{
+//!     "fr": 30, "ip": 0, "op": 60, "w": 512, "h": 512,
+//!     "layers": [{
+//!         "nm": "Circle", "ind": 1, "ip": 0, "op": 60, "st": 0,
+//!         "ks": {
+//!             "o": { "a": 1, "k": [
+//!                 { "t": 0, "s": [0], "o": { "x": [0.33], "y": [0] }, "i": { "x": [0.67], "y": [1] } },
+//!                 { "t": 30, "s": [100] }
+//!             ] },
+//!             "p": { "a": 0, "k": [256, 256, 0] }
+//!         }
+//!     }]
+//! }"#).unwrap();
+//!
+//! let mut opacity = animation.layers[0].transform.opacity.clone();
+//! assert_eq!(opacity.duration(), 1.0);
+//!
+//! opacity.advance_to(1.0);
+//! assert_eq!(opacity.now(), 1.0);
+//! ```
+
+use alloc::{
+	boxed::Box,
+	format,
+	string::{String, ToString},
+	vec,
+	vec::Vec,
+};
+use core::fmt;
+use serde_json::Value;
+
+use crate::{
+	functions::{BezierCurve, Hold, Linear},
+	AnimationSequence, DynEasingFunction, EasingFunction, Float, Keyframe, Vector2, Vector3,
+};
+
+// Number of keyframes a segment along a curved spatial path is split into
+const SPATIAL_SAMPLES: usize = 16;
+// Number of points used to estimate the length of a curved spatial path
+const PATH_LENGTH_SAMPLES: usize = 64;
+
+/// Keyframe with the components of a value, converted to a [`LottieValue`] once the whole property has been read
+struct RawKeyframe {
+	value: Vec<f64>,
+	time: f64,
+	function: DynEasingFunction,
+}
+
+impl RawKeyframe {
+	#[inline]
+	fn new(value: Vec<f64>, time: f64, function: impl EasingFunction + Send + Sync + 'static) -> Self {
+		RawKeyframe {
+			value,
+			time,
+			function: Box::new(function),
+		}
+	}
+}
+
+/// Category of Lottie import error
+#[derive(Debug)]
+pub enum LottieError {
+	/// The file is not valid JSON, contains the message of the JSON parser
+	InvalidJson(String),
+	/// A required value is missing or has the wrong type, contains the path to the value (e.g. `layers[0].ks.o.k[1].t`)
+	InvalidValue(String),
+}
+
+impl fmt::Display for LottieError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			LottieError::InvalidJson(message) => write!(f, "invalid JSON: {}", message),
+			LottieError::InvalidValue(path) => write!(f, "missing or invalid value at `{}`", path),
+		}
+	}
+}
+
+/// Values that animated Lottie properties can be read as
+pub trait LottieValue: Sized {
+	/// Creates a value from the components of a Lottie value, returns `None` if there are not enough components
+	fn from_components(components: &[f64]) -> Option<Self>;
+}
+
+impl LottieValue for f64 {
+	#[inline]
+	fn from_components(components: &[f64]) -> Option<Self> {
+		components.first().copied()
+	}
+}
+
+impl LottieValue for Vector2<f64> {
+	#[inline]
+	fn from_components(components: &[f64]) -> Option<Self> {
+		match *components {
+			[x, y, ..] => Some(Vector2 { x, y }),
+			_ => None,
+		}
+	}
+}
+
+impl LottieValue for Vector3<f64> {
+	/// A missing Z component is zero, since 2D layers only have X and Y
+	#[inline]
+	fn from_components(components: &[f64]) -> Option<Self> {
+		match *components {
+			[x, y, z, ..] => Some(Vector3 { x, y, z }),
+			[x, y] => Some(Vector3 { x, y, z: 0.0 }),
+			_ => None,
+		}
+	}
+}
+
+impl LottieValue for Vec<f64> {
+	#[inline]
+	fn from_components(components: &[f64]) -> Option<Self> {
+		Some(components.to_vec())
+	}
+}
+
+/// A Lottie animation with the animated properties of its layers
+#[derive(Clone, Debug)]
+pub struct LottieAnimation {
+	/// Frames per second
+	pub frame_rate: f64,
+	/// Duration in seconds, from the in point to the out point of the animation
+	pub duration: f64,
+	/// Width in pixels
+	pub width: f64,
+	/// Height in pixels
+	pub height: f64,
+	/// Layers in the order they appear in the file (top to bottom)
+	pub layers: Vec<LottieLayer>,
+}
+
+/// A layer of a Lottie animation
+#[derive(Clone, Debug)]
+pub struct LottieLayer {
+	/// Name of the layer (`nm`)
+	pub name: String,
+	/// Index used by other layers to refer to this layer (`ind`)
+	pub index: Option<i64>,
+	/// Index of the parent layer (`parent`)
+	pub parent: Option<i64>,
+	/// Time in seconds when the layer becomes visible
+	pub in_time: f64,
+	/// Time in seconds when the layer stops being visible
+	pub out_time: f64,
+	/// Transform and opacity of the layer
+	pub transform: LottieTransform,
+}
+
+/// Position of a layer, which can be animated with separate keyframes for X and Y
+#[derive(Clone, Debug)]
+pub enum LottiePosition {
+	/// X and Y are animated together
+	Combined(AnimationSequence<Vector2<f64>>),
+	/// X and Y are animated separately (`"s": true` in the file)
+	Split {
+		/// X position
+		x: AnimationSequence<f64>,
+		/// Y position
+		y: AnimationSequence<f64>,
+	},
+}
+
+/// Transform of a layer (`ks`), properties that aren't in the file have a single keyframe with the default value
+#[derive(Clone, Debug)]
+pub struct LottieTransform {
+	/// Point in the layer that it is positioned, scaled and rotated around, in pixels
+	pub anchor: AnimationSequence<Vector2<f64>>,
+	/// Position in pixels
+	pub position: LottiePosition,
+	/// Scale as a factor, i.e. 100% in the file is 1.0
+	pub scale: AnimationSequence<Vector2<f64>>,
+	/// Rotation (around Z for 3D layers) in degrees
+	pub rotation: AnimationSequence<f64>,
+	/// Skew in degrees
+	pub skew: AnimationSequence<f64>,
+	/// Direction of the skew in degrees
+	pub skew_axis: AnimationSequence<f64>,
+	/// Opacity from 0.0 to 1.0, i.e. 100% in the file is 1.0
+	pub opacity: AnimationSequence<f64>,
+}
+
+/// Parses a Lottie file and reads the transform and opacity of every layer.
+///
+/// Times are in seconds from the in point of the animation and take the start time and time stretch of each layer into account.
+pub fn parse_lottie(json: &str) -> Result<LottieAnimation, LottieError> {
+	let root: Value = serde_json::from_str(json).map_err(|error| LottieError::InvalidJson(error.to_string()))?;
+
+	let frame_rate = number(&root, "fr", "")?;
+	let in_point = number(&root, "ip", "")?;
+	let out_point = number(&root, "op", "")?;
+	if frame_rate <= 0.0 {
+		return Err(invalid_value("", "fr"));
+	}
+
+	let layers = match root.get("layers") {
+		Some(Value::Array(layers)) => layers,
+		None => &[][..],
+		_ => return Err(invalid_value("", "layers")),
+	};
+
+	let layers = layers
+		.iter()
+		.enumerate()
+		.map(|(i, layer)| {
+			let path = format!("layers[{}]", i);
+			let time = LayerTime {
+				frame_rate,
+				in_point,
+				start: optional_number(layer, "st", &path)?.unwrap_or(0.0),
+				stretch: optional_number(layer, "sr", &path)?.unwrap_or(1.0),
+			};
+
+			Ok(LottieLayer {
+				name: layer.get("nm").and_then(Value::as_str).unwrap_or_default().to_string(),
+				index: layer.get("ind").and_then(Value::as_i64),
+				parent: layer.get("parent").and_then(Value::as_i64),
+				in_time: (number(layer, "ip", &path)? - in_point) / frame_rate,
+				out_time: (number(layer, "op", &path)? - in_point) / frame_rate,
+				transform: transform(layer.get("ks"), &time, &format!("{}.ks", path))?,
+			})
+		})
+		.collect::<Result<_, LottieError>>()?;
+
+	Ok(LottieAnimation {
+		frame_rate,
+		duration: (out_point - in_point) / frame_rate,
+		width: optional_number(&root, "w", "")?.unwrap_or(0.0),
+		height: optional_number(&root, "h", "")?.unwrap_or(0.0),
+		layers,
+	})
+}
+
+/// Parses a single animated property, such as `{ "a": 1, "k": [{ "t": 0, "s": [0] }, { "t": 30, "s": [100] }] }`.
+///
+/// Frame 0 is at time zero and the values are read as they are in the file.
+///
+/// ```rust
+/// use keyframe::{lottie::parse_property, mint::Vector2};
+///
+/// let position = parse_property::<Vector2<f64>>(r#"{ "a": 1, "k": [
+///     { "t": 0, "s": [0, 0], "h": 1 },
+///     { "t": 12, "s": [100, 50] }
+/// ] }"#, 24.0).unwrap();
+///
+/// assert_eq!(position.duration(), 0.5);
+/// ```
+pub fn parse_property<T: LottieValue>(json: &str, frame_rate: f64) -> Result<AnimationSequence<T>, LottieError> {
+	let property: Value = serde_json::from_str(json).map_err(|error| LottieError::InvalidJson(error.to_string()))?;
+	let time = LayerTime {
+		frame_rate,
+		in_point: 0.0,
+		start: 0.0,
+		stretch: 1.0,
+	};
+
+	animated_property(&property, &time, "")
+}
+
+/// Converts frames in the time of a layer to seconds in the time of the animation
+struct LayerTime {
+	frame_rate: f64,
+	in_point: f64,
+	start: f64,
+	stretch: f64,
+}
+
+impl LayerTime {
+	#[inline]
+	fn seconds(&self, frame: f64) -> f64 {
+		(frame * self.stretch + self.start - self.in_point) / self.frame_rate
+	}
+}
+
+fn transform(transform: Option<&Value>, time: &LayerTime, path: &str) -> Result<LottieTransform, LottieError> {
+	let property = |name: &str| transform.and_then(|transform| transform.get(name));
+	let path = |name: &str| format!("{}.{}", path, name);
+
+	let animated_or = |name: &str, default: &[f64], factor: f64| -> Result<Vec<RawKeyframe>, LottieError> {
+		let mut keyframes = match property(name) {
+			Some(property) => keyframes(property, time, &path(name))?,
+			None => vec![RawKeyframe::new(default.to_vec(), 0.0, Linear)],
+		};
+		if factor != 1.0 {
+			for keyframe in keyframes.iter_mut() {
+				keyframe.value.iter_mut().for_each(|value| *value *= factor);
+			}
+		}
+		Ok(keyframes)
+	};
+
+	let position = match property("p") {
+		Some(split) if split.get("s").and_then(Value::as_bool) == Some(true) => LottiePosition::Split {
+			x: convert(split_axis(split, "x", time, &path("p"))?, &path("p.x"))?,
+			y: convert(split_axis(split, "y", time, &path("p"))?, &path("p.y"))?,
+		},
+		_ => LottiePosition::Combined(convert(animated_or("p", &[0.0, 0.0], 1.0)?, &path("p"))?),
+	};
+
+	// 3D layers have a rotation for every axis instead of "r"
+	let rotation = if property("r").is_none() && property("rz").is_some() {
+		"rz"
+	} else {
+		"r"
+	};
+
+	Ok(LottieTransform {
+		anchor: convert(animated_or("a", &[0.0, 0.0], 1.0)?, &path("a"))?,
+		position,
+		scale: convert(animated_or("s", &[100.0, 100.0], 0.01)?, &path("s"))?,
+		rotation: convert(animated_or(rotation, &[0.0], 1.0)?, &path(rotation))?,
+		skew: convert(animated_or("sk", &[0.0], 1.0)?, &path("sk"))?,
+		skew_axis: convert(animated_or("sa", &[0.0], 1.0)?, &path("sa"))?,
+		opacity: convert(animated_or("o", &[100.0], 0.01)?, &path("o"))?,
+	})
+}
+
+fn split_axis(position: &Value, axis: &str, time: &LayerTime, path: &str) -> Result<Vec<RawKeyframe>, LottieError> {
+	let path = format!("{}.{}", path, axis);
+	match position.get(axis) {
+		Some(property) => keyframes(property, time, &path),
+		None => Err(LottieError::InvalidValue(path)),
+	}
+}
+
+/// Converts keyframes with components to keyframes with a Lottie value
+fn convert<T: LottieValue>(keyframes: Vec<RawKeyframe>, path: &str) -> Result<AnimationSequence<T>, LottieError> {
+	keyframes
+		.into_iter()
+		.map(|keyframe| {
+			let value = T::from_components(&keyframe.value).ok_or_else(|| invalid_value(path, "k"))?;
+			Ok(Keyframe::new_dynamic(value, keyframe.time, keyframe.function))
+		})
+		.collect::<Result<Vec<_>, _>>()
+		.map(AnimationSequence::from)
+}
+
+fn animated_property<T: LottieValue>(
+	property: &Value,
+	time: &LayerTime,
+	path: &str,
+) -> Result<AnimationSequence<T>, LottieError> {
+	convert(keyframes(property, time, path)?, path)
+}
+
+/// Reads the keyframes of a property as lists of components
+fn keyframes(property: &Value, time: &LayerTime, path: &str) -> Result<Vec<RawKeyframe>, LottieError> {
+	let invalid = |name: &str| invalid_value(path, name);
+
+	let value = property.get("k").ok_or_else(|| invalid("k"))?;
+	let animated = match property.get("a") {
+		Some(a) => a.as_f64() == Some(1.0) || a.as_bool() == Some(true),
+		// Older files don't have "a", animated properties are lists of objects
+		None => matches!(value, Value::Array(keyframes) if keyframes.first().map_or(false, Value::is_object)),
+	};
+
+	if !animated {
+		let value = components(value).ok_or_else(|| invalid("k"))?;
+		return Ok(vec![RawKeyframe::new(value, 0.0, Linear)]);
+	}
+
+	let list = value
+		.as_array()
+		.filter(|list| !list.is_empty())
+		.ok_or_else(|| invalid("k"))?;
+	let mut keyframes = Vec::with_capacity(list.len());
+	let mut previous_end: Option<Vec<f64>> = None;
+
+	for (i, keyframe) in list.iter().enumerate() {
+		let invalid = |name: &str| invalid(&format!("k[{}].{}", i, name));
+
+		let frame = keyframe.get("t").and_then(Value::as_f64).ok_or_else(|| invalid("t"))?;
+		// Older files don't repeat the value at the start of a keyframe, it is the end value ("e") of the previous keyframe
+		let start = match keyframe.get("s") {
+			Some(value) => components(value).ok_or_else(|| invalid("s"))?,
+			None => previous_end.take().ok_or_else(|| invalid("s"))?,
+		};
+		previous_end = match keyframe.get("e") {
+			Some(value) => Some(components(value).ok_or_else(|| invalid("e"))?),
+			None => None,
+		};
+
+		let next = list.get(i + 1);
+		let end = match (next.and_then(|next| next.get("s")), &previous_end) {
+			(Some(value), _) => components(value),
+			(None, Some(end)) => Some(end.clone()),
+			(None, None) => None,
+		};
+
+		let seconds = time.seconds(frame);
+		let hold = keyframe.get("h").and_then(Value::as_f64) == Some(1.0);
+		let easing = easing(keyframe);
+
+		// A segment along a curved path is split into several keyframes, the last keyframe doesn't have a segment
+		match (next, end, spatial_tangents(keyframe)) {
+			(Some(next), Some(end), Some((out_tangent, in_tangent))) if !hold => {
+				let next_frame = next.get("t").and_then(Value::as_f64).ok_or_else(|| invalid("t"))?;
+				let next_seconds = time.seconds(next_frame);
+				let path = SpatialPath::new(&start, &out_tangent, &in_tangent, &end);
+
+				for sample in 0..SPATIAL_SAMPLES {
+					let x = sample as f64 / SPATIAL_SAMPLES as f64;
+					let value = path.at(easing.y(x));
+					keyframes.push(RawKeyframe::new(value, seconds + (next_seconds - seconds) * x, Linear));
+				}
+			}
+			_ if hold => keyframes.push(RawKeyframe::new(start, seconds, Hold)),
+			_ => keyframes.push(RawKeyframe {
+				value: start,
+				time: seconds,
+				function: easing,
+			}),
+		}
+	}
+
+	// Before the first keyframe the property has the value of the first keyframe
+	if let Some(first) = keyframes.first() {
+		if first.time > 0.0 {
+			let value = first.value.clone();
+			keyframes.insert(0, RawKeyframe::new(value, 0.0, Hold));
+		}
+	}
+
+	Ok(keyframes)
+}
+
+/// Reads a number or a list of numbers
+fn components(value: &Value) -> Option<Vec<f64>> {
+	match value {
+		Value::Number(number) => Some(vec![number.as_f64()?]),
+		Value::Array(list) => list.iter().map(Value::as_f64).collect(),
+		_ => None,
+	}
+}
+
+/// Reads the first value of a number or a list of numbers, Lottie has a handle for every dimension of a value
+fn first_component(value: Option<&Value>) -> Option<f64> {
+	match value? {
+		Value::Number(number) => number.as_f64(),
+		Value::Array(list) => list.first()?.as_f64(),
+		_ => None,
+	}
+}
+
+/// The easing function from the out handle (`o`) of a keyframe to the in handle (`i`) of the next keyframe
+fn easing(keyframe: &Value) -> DynEasingFunction {
+	let handle = |name: &str| {
+		let handle = keyframe.get(name)?;
+		Some(Vector2 {
+			x: first_component(handle.get("x"))?.clamp(0.0, 1.0),
+			y: first_component(handle.get("y"))?,
+		})
+	};
+
+	match (handle("o"), handle("i")) {
+		(Some(out_handle), Some(in_handle)) => Box::new(BezierCurve::from(out_handle, in_handle)),
+		_ => Box::new(Linear),
+	}
+}
+
+/// The out (`to`) and in (`ti`) tangents of a keyframe, or `None` if the path is a straight line
+fn spatial_tangents(keyframe: &Value) -> Option<(Vec<f64>, Vec<f64>)> {
+	let out_tangent = components(keyframe.get("to")?)?;
+	let in_tangent = components(keyframe.get("ti")?)?;
+
+	if out_tangent
+		.iter()
+		.chain(in_tangent.iter())
+		.all(|&component| component == 0.0)
+	{
+		None
+	} else {
+		Some((out_tangent, in_tangent))
+	}
+}
+
+/// Cubic Bézier path between two values, which is followed at a constant speed
+struct SpatialPath {
+	points: [Vec<f64>; 4],
+	// Distance along the path at evenly spaced parameters
+	lengths: Vec<f64>,
+}
+
+impl SpatialPath {
+	fn new(start: &[f64], out_tangent: &[f64], in_tangent: &[f64], end: &[f64]) -> Self {
+		let offset = |point: &[f64], tangent: &[f64]| {
+			point
+				.iter()
+				.enumerate()
+				.map(|(i, value)| value + tangent.get(i).copied().unwrap_or(0.0))
+				.collect::<Vec<f64>>()
+		};
+
+		let mut path = SpatialPath {
+			points: [
+				start.to_vec(),
+				offset(start, out_tangent),
+				offset(end, in_tangent),
+				end.to_vec(),
+			],
+			lengths: Vec::with_capacity(PATH_LENGTH_SAMPLES + 1),
+		};
+
+		let mut length = 0.0;
+		let mut previous = path.point(0.0);
+		path.lengths.push(0.0);
+		for i in 1..=PATH_LENGTH_SAMPLES {
+			let point = path.point(i as f64 / PATH_LENGTH_SAMPLES as f64);
+			length += distance(&previous, &point);
+			path.lengths.push(length);
+			previous = point;
+		}
+
+		path
+	}
+
+	/// The point at a parameter of the Bézier curve
+	fn point(&self, t: f64) -> Vec<f64> {
+		let [p0, p1, p2, p3] = &self.points;
+		let u = 1.0 - t;
+
+		(0..p0.len().min(p3.len()))
+			.map(|i| u * u * u * p0[i] + 3.0 * u * u * t * p1[i] + 3.0 * u * t * t * p2[i] + t * t * t * p3[i])
+			.collect()
+	}
+
+	/// The point at a fraction of the length of the path, fractions outside of 0.0 to 1.0 stay at the start or end
+	fn at(&self, fraction: f64) -> Vec<f64> {
+		let total = self.lengths[PATH_LENGTH_SAMPLES];
+		if total <= 0.0 {
+			return self.point(fraction.clamp(0.0, 1.0));
+		}
+
+		let target = fraction.clamp(0.0, 1.0) * total;
+		let i = self
+			.lengths
+			.iter()
+			.position(|&length| length >= target)
+			.unwrap_or(PATH_LENGTH_SAMPLES)
+			.clamp(1, PATH_LENGTH_SAMPLES);
+		let (before, after) = (self.lengths[i - 1], self.lengths[i]);
+		let within = if after > before {
+			(target - before) / (after - before)
+		} else {
+			0.0
+		};
+
+		self.point((i as f64 - 1.0 + within) / PATH_LENGTH_SAMPLES as f64)
+	}
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+	Float::sqrt(a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum::<f64>())
+}
+
+/// Error for the value `name` in the object at `path`
+fn invalid_value(path: &str, name: &str) -> LottieError {
+	if path.is_empty() {
+		LottieError::InvalidValue(name.to_string())
+	} else {
+		LottieError::InvalidValue(format!("{}.{}", path, name))
+	}
+}
+
+fn optional_number(object: &Value, name: &str, path: &str) -> Result<Option<f64>, LottieError> {
+	match object.get(name) {
+		None => Ok(None),
+		Some(value) => value.as_f64().map(Some).ok_or_else(|| invalid_value(path, name)),
+	}
+}
+
+fn number(object: &Value, name: &str, path: &str) -> Result<f64, LottieError> {
+	optional_number(object, name, path)?.ok_or_else(|| invalid_value(path, name))
+}
@@ -0,0 +1,72 @@
+use nalgebra::{
+	Complex, Isometry, Point, Quaternion, SVector, Scalar, Translation, Unit, UnitComplex, UnitQuaternion,
+};
+
+use crate::{as_f64, as_t, rotation, CanTween, Float};
+
+impl<T: CanTween + Scalar, const D: usize> CanTween for SVector<T, D> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		from.zip_map(&to, |f, t| T::ease(f, t, time))
+	}
+}
+
+impl<T: CanTween + Scalar, const D: usize> CanTween for Point<T, D> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Point {
+			coords: SVector::ease(from.coords, to.coords, time),
+		}
+	}
+}
+
+impl<T: CanTween + Scalar, const D: usize> CanTween for Translation<T, D> {
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Translation {
+			vector: SVector::ease(from.vector, to.vector, time),
+		}
+	}
+}
+
+impl<T: Float + Scalar> CanTween for UnitQuaternion<T> {
+	/// Spherical linear interpolation (slerp) between two rotations.
+	/// The shortest path is always taken and the returned quaternion is normalized.
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		// The coordinates are stored as [i, j, k, w], the same order as the quaternions in the rotation module
+		let convert = |q: Self| {
+			let c = &q.as_ref().coords;
+			[as_f64(c[0]), as_f64(c[1]), as_f64(c[2]), as_f64(c[3])]
+		};
+		let [x, y, z, w] = rotation::slerp(convert(from), convert(to), as_f64(time));
+
+		Unit::new_unchecked(Quaternion::new(as_t(w), as_t(x), as_t(y), as_t(z)))
+	}
+}
+
+impl<T: Float + Scalar> CanTween for UnitComplex<T> {
+	/// Interpolates the angle of two 2D rotations, always taking the shortest path
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		let (from_re, from_im) = (as_f64(from.re), as_f64(from.im));
+		let (to_re, to_im) = (as_f64(to.re), as_f64(to.im));
+
+		// Angle of the rotation from `from` to `to`, which is always between -PI and PI
+		let delta = (to_im * from_re - to_re * from_im).atan2(to_re * from_re + to_im * from_im);
+		let angle = from_im.atan2(from_re) + delta * as_f64(time);
+
+		Unit::new_unchecked(Complex::new(as_t(angle.cos()), as_t(angle.sin())))
+	}
+}
+
+impl<T: CanTween + Scalar, R: CanTween, const D: usize> CanTween for Isometry<T, R, D> {
+	/// Interpolates the rotation and translation separately
+	#[inline]
+	fn ease(from: Self, to: Self, time: impl Float) -> Self {
+		Isometry {
+			rotation: R::ease(from.rotation, to.rotation, time),
+			translation: Translation::ease(from.translation, to.translation, time),
+		}
+	}
+}
@@ -0,0 +1,201 @@
+use alloc::{
+	boxed::Box,
+	collections::BTreeMap,
+	string::{String, ToString},
+	sync::Arc,
+};
+use core::fmt;
+
+use crate::{functions::*, EasingFunction, Float};
+
+/// Easing function created by an [`EasingRegistry`]
+pub type DynEasingFunction = Box<dyn EasingFunction + Send + Sync>;
+
+type Constructor = Arc<dyn Fn(&[f64]) -> Option<DynEasingFunction> + Send + Sync>;
+
+/// Category of easing registry error
+#[derive(Debug)]
+pub enum EasingRegistryError {
+	/// No function has been registered with this name
+	UnknownFunction(String),
+	/// The function was found but the parameters were not accepted by it
+	InvalidParameters(String),
+}
+
+impl fmt::Display for EasingRegistryError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			EasingRegistryError::UnknownFunction(name) => write!(f, "unknown easing function `{}`", name),
+			EasingRegistryError::InvalidParameters(name) => {
+				write!(f, "invalid parameters for easing function `{}`", name)
+			}
+		}
+	}
+}
+
+/// Maps names and parameters to easing functions, e.g. for loading animations from config files or scripts
+///
+/// ```rust
+/// use keyframe::{EasingFunction, EasingRegistry};
+///
+/// struct Smoothstep;
+/// impl EasingFunction for Smoothstep {
+///     fn y(&self, x: f64) -> f64 {
+///         x * x * (3.0 - 2.0 * x)
+///     }
+/// }
+///
+/// let mut registry = EasingRegistry::new();
+/// registry.register_static("smoothstep", || Smoothstep);
+///
+/// let cubic = registry.create("EaseInOutCubic", &[]).unwrap();
+/// let bezier = registry.create("bezier", &[0.25, 0.1, 0.25, 1.0]).unwrap();
+/// let smoothstep = registry.create("smoothstep", &[]).unwrap();
+///
+/// assert_eq!(smoothstep.y(0.5), 0.5);
+/// assert!(registry.create("bezier", &[0.25]).is_err());
+/// ```
+#[derive(Clone)]
+pub struct EasingRegistry {
+	functions: BTreeMap<String, Constructor>,
+}
+
+impl EasingRegistry {
+	/// Creates a new registry containing all built-in functions.
+	///
+	/// Functions without parameters are registered with the name of their type (e.g. `"EaseInOutCubic"`).
+	/// [`BezierCurve`] is registered as `"BezierCurve"` and `"bezier"` and takes the control points as `[x1, y1, x2, y2]`.
+	/// [`Keyframes`] is registered as `"Keyframes"` and takes its sample table.
+	/// [`Steps`] is registered as `"Steps"` and takes `[steps]` or `[steps, jump_start, jump_end]` where the jumps are 0 or 1.
+	/// [`PiecewiseLinear`] is registered as `"PiecewiseLinear"` and takes its points as `[input, output, input, output, ...]`.
+	pub fn new() -> Self {
+		let mut registry = EasingRegistry::empty();
+
+		registry.register_static("Linear", || Linear);
+		registry.register_static("Step", || Step);
+		registry.register_static("Hold", || Hold);
+		registry.register_static("EaseInQuad", || EaseInQuad);
+		registry.register_static("EaseOutQuad", || EaseOutQuad);
+		registry.register_static("EaseInOutQuad", || EaseInOutQuad);
+		registry.register_static("EaseInCubic", || EaseInCubic);
+		registry.register_static("EaseOutCubic", || EaseOutCubic);
+		registry.register_static("EaseInOutCubic", || EaseInOutCubic);
+		registry.register_static("EaseInQuart", || EaseInQuart);
+		registry.register_static("EaseOutQuart", || EaseOutQuart);
+		registry.register_static("EaseInOutQuart", || EaseInOutQuart);
+		registry.register_static("EaseInQuint", || EaseInQuint);
+		registry.register_static("EaseOutQuint", || EaseOutQuint);
+		registry.register_static("EaseInOutQuint", || EaseInOutQuint);
+		registry.register_static("EaseIn", || EaseIn);
+		registry.register_static("EaseOut", || EaseOut);
+		registry.register_static("EaseInOut", || EaseInOut);
+
+		#[cfg(feature = "mint_types")]
+		{
+			let bezier = |parameters: &[f64]| match *parameters {
+				[x1, y1, x2, y2] => Some(Box::new(BezierCurve::from(
+					crate::Vector2 { x: x1, y: y1 },
+					crate::Vector2 { x: x2, y: y2 },
+				)) as DynEasingFunction),
+				_ => None,
+			};
+			registry.register("BezierCurve", bezier);
+			registry.register("bezier", bezier);
+		}
+
+		registry.register("Keyframes", |parameters| {
+			Keyframes::from_sample_table(parameters).map(|keyframes| Box::new(keyframes) as DynEasingFunction)
+		});
+
+		registry.register("Steps", |parameters| {
+			let position = match *parameters {
+				[_] => StepPosition::JumpEnd,
+				[_, start, end] if (start == 0.0 || start == 1.0) && (end == 0.0 || end == 1.0) => {
+					StepPosition::from_jumps(start == 1.0, end == 1.0)
+				}
+				_ => return None,
+			};
+			let steps = parameters[0];
+			let minimum = if position == StepPosition::JumpNone { 2.0 } else { 1.0 };
+
+			if Float::fract(steps) == 0.0 && steps >= minimum && steps <= u32::MAX as f64 {
+				Some(Box::new(Steps::new(steps as u32, position)) as DynEasingFunction)
+			} else {
+				None
+			}
+		});
+
+		registry.register("PiecewiseLinear", |parameters| {
+			if parameters.len() % 2 == 0 {
+				let points = parameters.chunks_exact(2).map(|point| [point[0], point[1]]).collect();
+				Some(Box::new(PiecewiseLinear::new(points)) as DynEasingFunction)
+			} else {
+				None
+			}
+		});
+
+		registry
+	}
+
+	/// Creates a new registry without any functions
+	#[inline]
+	pub fn empty() -> Self {
+		EasingRegistry {
+			functions: BTreeMap::new(),
+		}
+	}
+
+	/// Registers a function that is created from a list of parameters, replacing any function with the same name.
+	/// The constructor returns `None` if the parameters are invalid.
+	pub fn register(
+		&mut self,
+		name: impl Into<String>,
+		constructor: impl Fn(&[f64]) -> Option<DynEasingFunction> + Send + Sync + 'static,
+	) {
+		self.functions.insert(name.into(), Arc::new(constructor));
+	}
+
+	/// Registers a function without parameters, replacing any function with the same name
+	pub fn register_static<F: EasingFunction + Send + Sync + 'static>(
+		&mut self,
+		name: impl Into<String>,
+		constructor: impl Fn() -> F + Send + Sync + 'static,
+	) {
+		self.register(name, move |parameters| {
+			if parameters.is_empty() {
+				Some(Box::new(constructor()) as DynEasingFunction)
+			} else {
+				None
+			}
+		});
+	}
+
+	/// Creates the function registered with this name from a list of parameters
+	pub fn create(&self, name: &str, parameters: &[f64]) -> Result<DynEasingFunction, EasingRegistryError> {
+		let constructor = self
+			.functions
+			.get(name)
+			.ok_or_else(|| EasingRegistryError::UnknownFunction(name.to_string()))?;
+
+		constructor(parameters).ok_or_else(|| EasingRegistryError::InvalidParameters(name.to_string()))
+	}
+
+	/// If a function has been registered with this name
+	#[inline]
+	pub fn contains(&self, name: &str) -> bool {
+		self.functions.contains_key(name)
+	}
+
+	/// The names of all registered functions in alphabetical order
+	#[inline]
+	pub fn names(&self) -> impl Iterator<Item = &str> {
+		self.functions.keys().map(String::as_str)
+	}
+}
+
+impl Default for EasingRegistry {
+	#[inline]
+	fn default() -> Self {
+		EasingRegistry::new()
+	}
+}
@@ -0,0 +1,204 @@
+// Needed for float math in no_std on compilers where it isn't part of core yet
+#[allow(unused_imports)]
+use num_traits::Float;
+
+// Quaternions are stored as [x, y, z, w] (same order as mint) and matrices as rows
+
+/// Unit quaternion that is used for interpolating rotations
+pub(crate) type Quaternion = [f64; 4];
+
+/// 3x3 rotation matrix, indexed as `m[row][column]`
+#[cfg(any(feature = "mint_types", feature = "glam", feature = "cgmath"))]
+pub(crate) type RotationMatrix = [[f64; 3]; 3];
+
+// Past this dot product the two rotations are so close that slerp becomes numerically unstable
+const SLERP_THRESHOLD: f64 = 0.9995;
+// Closer than this to a pole of the middle angle and the first and third Euler angles describe the same axis
+#[cfg(feature = "mint_types")]
+const GIMBAL_LOCK_THRESHOLD: f64 = 1.0 - 1e-9;
+
+pub(crate) const IDENTITY: Quaternion = [0.0, 0.0, 0.0, 1.0];
+
+#[inline]
+fn dot(a: Quaternion, b: Quaternion) -> f64 {
+	a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3]
+}
+
+pub(crate) fn normalize(q: Quaternion) -> Quaternion {
+	match dot(q, q).sqrt() {
+		length if length == 0.0 || !length.is_finite() => IDENTITY,
+		length => [q[0] / length, q[1] / length, q[2] / length, q[3] / length],
+	}
+}
+
+/// Hamilton product, applies `b` first and then `a`
+#[cfg(feature = "mint_types")]
+pub(crate) fn multiply(a: Quaternion, b: Quaternion) -> Quaternion {
+	[
+		a[3] * b[0] + a[0] * b[3] + a[1] * b[2] - a[2] * b[1],
+		a[3] * b[1] - a[0] * b[2] + a[1] * b[3] + a[2] * b[0],
+		a[3] * b[2] + a[0] * b[1] - a[1] * b[0] + a[2] * b[3],
+		a[3] * b[3] - a[0] * b[0] - a[1] * b[1] - a[2] * b[2],
+	]
+}
+
+/// Rotation of `angle` radians around the X (0), Y (1) or Z (2) axis
+#[cfg(feature = "mint_types")]
+fn from_axis_angle(axis: usize, angle: f64) -> Quaternion {
+	let mut q = [0.0, 0.0, 0.0, (angle / 2.0).cos()];
+	q[axis] = (angle / 2.0).sin();
+	q
+}
+
+/// Spherical linear interpolation between two rotations, always taking the shortest path
+pub(crate) fn slerp(from: Quaternion, to: Quaternion, time: f64) -> Quaternion {
+	let from = normalize(from);
+	let mut to = normalize(to);
+
+	// q and -q are the same rotation, flipping one of them makes sure we don't take the long way around
+	let mut cos_theta = dot(from, to);
+	if cos_theta < 0.0 {
+		to = [-to[0], -to[1], -to[2], -to[3]];
+		cos_theta = -cos_theta;
+	}
+
+	let (a, b) = if cos_theta > SLERP_THRESHOLD {
+		(1.0 - time, time)
+	} else {
+		let theta = cos_theta.acos();
+		let sin_theta = theta.sin();
+		(
+			((1.0 - time) * theta).sin() / sin_theta,
+			(time * theta).sin() / sin_theta,
+		)
+	};
+
+	normalize([
+		a * from[0] + b * to[0],
+		a * from[1] + b * to[1],
+		a * from[2] + b * to[2],
+		a * from[3] + b * to[3],
+	])
+}
+
+#[cfg(any(feature = "mint_types", feature = "glam", feature = "cgmath"))]
+pub(crate) fn to_matrix(q: Quaternion) -> RotationMatrix {
+	let [x, y, z, w] = normalize(q);
+
+	[
+		[
+			1.0 - 2.0 * (y * y + z * z),
+			2.0 * (x * y - w * z),
+			2.0 * (x * z + w * y),
+		],
+		[
+			2.0 * (x * y + w * z),
+			1.0 - 2.0 * (x * x + z * z),
+			2.0 * (y * z - w * x),
+		],
+		[
+			2.0 * (x * z - w * y),
+			2.0 * (y * z + w * x),
+			1.0 - 2.0 * (x * x + y * y),
+		],
+	]
+}
+
+#[cfg(any(feature = "mint_types", feature = "glam", feature = "cgmath"))]
+pub(crate) fn from_matrix(m: RotationMatrix) -> Quaternion {
+	let trace = m[0][0] + m[1][1] + m[2][2];
+
+	// Pick the largest component to divide by for numerical stability
+	normalize(if trace > 0.0 {
+		let s = (trace + 1.0).sqrt() * 2.0;
+		[
+			(m[2][1] - m[1][2]) / s,
+			(m[0][2] - m[2][0]) / s,
+			(m[1][0] - m[0][1]) / s,
+			s / 4.0,
+		]
+	} else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+		let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+		[
+			s / 4.0,
+			(m[0][1] + m[1][0]) / s,
+			(m[0][2] + m[2][0]) / s,
+			(m[2][1] - m[1][2]) / s,
+		]
+	} else if m[1][1] > m[2][2] {
+		let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+		[
+			(m[0][1] + m[1][0]) / s,
+			s / 4.0,
+			(m[1][2] + m[2][1]) / s,
+			(m[0][2] - m[2][0]) / s,
+		]
+	} else {
+		let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+		[
+			(m[0][2] + m[2][0]) / s,
+			(m[1][2] + m[2][1]) / s,
+			s / 4.0,
+			(m[1][0] - m[0][1]) / s,
+		]
+	})
+}
+
+/// Order in which three Euler angles (a, b, c) are applied.
+/// Extrinsic rotations are the same as intrinsic rotations in the reverse order with a and c swapped.
+#[cfg(feature = "mint_types")]
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum EulerOrder {
+	/// Rotation around X, then the new Y, then the new Z axis
+	IntrinsicXYZ,
+	/// Rotation around Z, then the new X, then the new Z axis
+	IntrinsicZXZ,
+	/// Rotation around Z, then the new Y, then the new X axis
+	IntrinsicZYX,
+}
+
+#[cfg(feature = "mint_types")]
+pub(crate) fn from_euler(order: EulerOrder, [a, b, c]: [f64; 3]) -> Quaternion {
+	let axes = match order {
+		EulerOrder::IntrinsicXYZ => [0, 1, 2],
+		EulerOrder::IntrinsicZXZ => [2, 0, 2],
+		EulerOrder::IntrinsicZYX => [2, 1, 0],
+	};
+
+	multiply(
+		multiply(from_axis_angle(axes[0], a), from_axis_angle(axes[1], b)),
+		from_axis_angle(axes[2], c),
+	)
+}
+
+#[cfg(feature = "mint_types")]
+pub(crate) fn to_euler(order: EulerOrder, q: Quaternion) -> [f64; 3] {
+	let m = to_matrix(q);
+
+	match order {
+		EulerOrder::IntrinsicXYZ => {
+			let sin_b = m[0][2].clamp(-1.0, 1.0);
+			if sin_b.abs() < GIMBAL_LOCK_THRESHOLD {
+				[(-m[1][2]).atan2(m[2][2]), sin_b.asin(), (-m[0][1]).atan2(m[0][0])]
+			} else {
+				[m[2][1].atan2(m[1][1]), sin_b.asin(), 0.0]
+			}
+		}
+		EulerOrder::IntrinsicZYX => {
+			let sin_b = (-m[2][0]).clamp(-1.0, 1.0);
+			if sin_b.abs() < GIMBAL_LOCK_THRESHOLD {
+				[m[1][0].atan2(m[0][0]), sin_b.asin(), m[2][1].atan2(m[2][2])]
+			} else {
+				[(-m[0][1]).atan2(m[1][1]), sin_b.asin(), 0.0]
+			}
+		}
+		EulerOrder::IntrinsicZXZ => {
+			let cos_b = m[2][2].clamp(-1.0, 1.0);
+			if cos_b.abs() < GIMBAL_LOCK_THRESHOLD {
+				[m[0][2].atan2(-m[1][2]), cos_b.acos(), m[2][0].atan2(m[2][1])]
+			} else {
+				[m[1][0].atan2(m[0][0]), cos_b.acos(), 0.0]
+			}
+		}
+	}
+}
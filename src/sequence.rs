@@ -1,8 +1,9 @@
 use alloc::vec::Vec;
-use core::iter::FromIterator;
+use core::{fmt, iter::FromIterator};
 use num_traits::Float;
 
 use crate::{
+	ease_with_scaled_time,
 	easing::{Keyframes, Linear},
 	CanTween, Keyframe,
 };
@@ -215,6 +216,28 @@ impl<T> AnimationSequence<T> {
 		}
 	}
 
+	/// Same as [`now`](#method.now), but writes the current value into `out` so that it can be reused between frames.
+	///
+	/// Like `now`, this requires `T` to implement `Clone`. Types that don't override [`CanTween::ease_into`] still clone
+	/// the keyframe values they are tweened between.
+	pub fn now_into(&self, out: &mut T)
+	where
+		T: CanTween + Clone + Default,
+	{
+		match self.pair() {
+			(Some(s1), Some(s2)) => s1.tween_into(s2, self.time, out),
+			(Some(s1), None) => out.clone_from(&s1.value),
+			// Same as `now`, which tweens linearly from the default value to the first keyframe
+			(None, Some(s2)) => T::ease_into(
+				&T::default(),
+				&s2.value,
+				ease_with_scaled_time(Linear, 0.0, 1.0, self.time, s2.time),
+				out,
+			),
+			(None, None) => *out = T::default(),
+		}
+	}
+
 	/// Advances this sequence by the duration specified.
 	///
 	/// Returns the remaining time (i.e. the amount that the specified duration went outside the bounds of the total duration of this sequence)
@@ -359,6 +382,38 @@ impl<T> From<Vec<Keyframe<T>>> for AnimationSequence<T> {
 	}
 }
 
+impl<T: PartialEq> PartialEq for AnimationSequence<T> {
+	/// Sequences are equal if they have the same keyframes and are at the same time
+	fn eq(&self, other: &Self) -> bool {
+		self.sequence == other.sequence && self.time == other.time
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for AnimationSequence<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("AnimationSequence")
+			.field("sequence", &self.sequence)
+			.field("time", &self.time)
+			.finish()
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for AnimationSequence<T> {
+	/// Serializes the keyframes of this sequence as a list, the current time is not included
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.sequence.serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for AnimationSequence<T> {
+	/// Deserializes a sequence from a list of keyframes, keyframes with the same time are discarded
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Vec::<Keyframe<T>>::deserialize(deserializer).map(AnimationSequence::from)
+	}
+}
+
 impl<T, I: Into<Keyframe<T>>> FromIterator<I> for AnimationSequence<T> {
 	/// Creates a new animation sequence from an iterator
 	fn from_iter<I2: IntoIterator<Item = I>>(iter: I2) -> Self {
@@ -395,3 +450,129 @@ macro_rules! keyframes {
 		AnimationSequence::from(vec)
 	}};
 }
+
+#[cfg(feature = "mint_types")]
+mod mint_type_impls {
+	use crate::{sequence::*, Point2, Point3, Vector2, Vector3};
+
+	// Distance on the X axis used to estimate the slope of an easing function
+	const TANGENT_EPSILON: f64 = 0.001;
+
+	impl<T> AnimationSequence<T> {
+		/// The pair of keyframes that decides the direction of travel at the current time,
+		/// and whether the easing function between them is currently moving backwards (e.g. overshooting).
+		///
+		/// # Note
+		///
+		/// Unlike [`pair`](#method.pair), the last pair of keyframes is returned when the sequence has finished
+		/// and the first pair is returned when the sequence has not reached the first keyframe.
+		fn travel_segment(&self) -> Option<(&Keyframe<T>, &Keyframe<T>, bool)> {
+			let (current, next) = match self.pair() {
+				(Some(current), Some(next)) => (current, next),
+				_ if self.keyframes() < 2 => return None,
+				(Some(_), None) => (
+					&self.sequence[self.keyframes() - 2],
+					&self.sequence[self.keyframes() - 1],
+				),
+				(None, _) => (&self.sequence[0], &self.sequence[1]),
+			};
+
+			let length = next.time() - current.time();
+			let x = if length > 0.0 {
+				((self.time - current.time()) / length).clamp(0.0, 1.0)
+			} else {
+				0.0
+			};
+			let slope = current.function().y((x + TANGENT_EPSILON).min(1.0))
+				- current.function().y((x - TANGENT_EPSILON).max(0.0));
+
+			Some((current, next, slope < 0.0))
+		}
+	}
+
+	fn normalize<V: Float, const N: usize>(mut v: [V; N], backwards: bool) -> Option<[V; N]> {
+		let length = v.iter().fold(V::zero(), |sum, c| sum + *c * *c).sqrt();
+		if length == V::zero() || !length.is_finite() {
+			return None;
+		}
+
+		let length = if backwards { -length } else { length };
+		for c in v.iter_mut() {
+			*c = *c / length;
+		}
+
+		Some(v)
+	}
+
+	fn tangent_2d<V: Float>(from: [V; 2], to: [V; 2], backwards: bool) -> Option<[V; 2]> {
+		normalize([to[0] - from[0], to[1] - from[1]], backwards)
+	}
+
+	fn tangent_3d<V: Float>(from: [V; 3], to: [V; 3], backwards: bool) -> Option<[V; 3]> {
+		normalize([to[0] - from[0], to[1] - from[1], to[2] - from[2]], backwards)
+	}
+
+	impl<V: Float> AnimationSequence<Vector2<V>> {
+		/// The normalized direction of travel at the current time of this sequence.
+		/// Returns `None` if the sequence has less than two keyframes or isn't moving between them.
+		///
+		/// The tangent is undefined before the first keyframe and after the last keyframe, where the value holds still
+		/// (this type doesn't implement `Default`, so only [`now_strict`](#method.now_strict) is available and `now` never
+		/// tweens from a default value). The direction of the first or last pair of keyframes is returned there instead.
+		pub fn now_tangent(&self) -> Option<Vector2<V>> {
+			let (current, next, backwards) = self.travel_segment()?;
+			tangent_2d(current.value().into(), next.value().into(), backwards).map(Vector2::from)
+		}
+
+		/// The angle in radians of [`now_tangent`](#method.now_tangent), measured counterclockwise from the positive X axis.
+		/// Useful for rotating a sprite so that it faces the direction it's moving in.
+		pub fn now_heading(&self) -> Option<V> {
+			self.now_tangent().map(|t| t.y.atan2(t.x))
+		}
+	}
+
+	impl<V: Float> AnimationSequence<Point2<V>> {
+		/// The normalized direction of travel at the current time of this sequence.
+		/// Returns `None` if the sequence has less than two keyframes or isn't moving between them.
+		///
+		/// The tangent is undefined before the first keyframe and after the last keyframe, where the value holds still
+		/// (this type doesn't implement `Default`, so only [`now_strict`](#method.now_strict) is available and `now` never
+		/// tweens from a default value). The direction of the first or last pair of keyframes is returned there instead.
+		pub fn now_tangent(&self) -> Option<Vector2<V>> {
+			let (current, next, backwards) = self.travel_segment()?;
+			tangent_2d(current.value().into(), next.value().into(), backwards).map(Vector2::from)
+		}
+
+		/// The angle in radians of [`now_tangent`](#method.now_tangent), measured counterclockwise from the positive X axis.
+		/// Useful for rotating a sprite so that it faces the direction it's moving in.
+		pub fn now_heading(&self) -> Option<V> {
+			self.now_tangent().map(|t| t.y.atan2(t.x))
+		}
+	}
+
+	impl<V: Float> AnimationSequence<Vector3<V>> {
+		/// The normalized direction of travel at the current time of this sequence.
+		/// Returns `None` if the sequence has less than two keyframes or isn't moving between them.
+		///
+		/// The tangent is undefined before the first keyframe and after the last keyframe, where the value holds still
+		/// (this type doesn't implement `Default`, so only [`now_strict`](#method.now_strict) is available and `now` never
+		/// tweens from a default value). The direction of the first or last pair of keyframes is returned there instead.
+		pub fn now_tangent(&self) -> Option<Vector3<V>> {
+			let (current, next, backwards) = self.travel_segment()?;
+			tangent_3d(current.value().into(), next.value().into(), backwards).map(Vector3::from)
+		}
+	}
+
+	impl<V: Float> AnimationSequence<Point3<V>> {
+		/// The normalized direction of travel at the current time of this sequence.
+		/// Returns `None` if the sequence has less than two keyframes or isn't moving between them.
+		///
+		/// The tangent is undefined before the first keyframe and after the last keyframe, where the value holds still
+		/// (this type doesn't implement `Default`, so only [`now_strict`](#method.now_strict) is available and `now` never
+		/// tweens from a default value). The direction of the first or last pair of keyframes is returned there instead.
+		pub fn now_tangent(&self) -> Option<Vector3<V>> {
+			let (current, next, backwards) = self.travel_segment()?;
+			tangent_3d(current.value().into(), next.value().into(), backwards).map(Vector3::from)
+		}
+	}
+}
@@ -0,0 +1,281 @@
+//! Headless plots of easing functions and animation sequences as SVG images.
+//!
+//! The plots have axes with labels at both ends, a marker at every keyframe and the curve itself:
+//!
+//! * [`plot_function`] draws an easing function from 0.0 to 1.0, overshooting functions extend the Y axis
+//! * [`plot_sequence`] draws the value of a 1D sequence over time
+//! * [`plot_path`] draws the path of a 2D sequence (`[T; 2]`, `mint::Vector2` or `mint::Point2`)
+//!
+//! ```rust
+//! use keyframe::{functions::EaseInOut, keyframes, svg::{plot_function, plot_sequence, SvgStyle}, AnimationSequence};
+//!
+//! let image = plot_function(&EaseInOut, &SvgStyle::default());
+//! assert!(image.starts_with("<svg"));
+//!
+//! let sequence: AnimationSequence<f64> = keyframes![(0.0, 0.0), (2.0, 0.5), (1.0, 1.5)];
+//! let image = plot_sequence(&sequence, &SvgStyle { width: 640.0, ..SvgStyle::default() });
+//! assert_eq!(image.matches("<circle").count(), 3);
+//! ```
+
+use alloc::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+use core::fmt::Write;
+
+use crate::{as_f64, AnimationSequence, CanTween, EasingFunction, Float};
+
+/// Size and colors of a plot. Colors can be anything that SVG accepts, e.g. `#F44336` or `red`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgStyle {
+	/// Width of the image in pixels
+	pub width: f64,
+	/// Height of the image in pixels
+	pub height: f64,
+	/// Space between the edges of the image and the axes, for the labels
+	pub margin: f64,
+	/// Number of line segments that the curve is made of
+	pub samples: usize,
+	/// Color of the background, `None` for a transparent background
+	pub background: Option<String>,
+	/// Color of the axes and their labels
+	pub axis_color: String,
+	/// Color of the curve
+	pub curve_color: String,
+	/// Color of the keyframe markers
+	pub marker_color: String,
+}
+
+impl Default for SvgStyle {
+	#[inline]
+	fn default() -> Self {
+		SvgStyle {
+			width: 400.0,
+			height: 300.0,
+			margin: 40.0,
+			samples: 200,
+			background: Some("#FFFFFF".to_string()),
+			axis_color: "#757575".to_string(),
+			curve_color: "#F44336".to_string(),
+			marker_color: "#2196F3".to_string(),
+		}
+	}
+}
+
+/// Plots an easing function from 0.0 to 1.0 with markers at the start and end
+pub fn plot_function(function: &(impl EasingFunction + ?Sized), style: &SvgStyle) -> String {
+	let samples = style.samples.max(1);
+	let curve: Vec<[f64; 2]> = (0..=samples)
+		.map(|i| {
+			let x = i as f64 / samples as f64;
+			[x, function.y(x)]
+		})
+		.collect();
+	let markers = [curve[0], curve[samples]];
+
+	plot(&curve, &markers, &[[0.0, 0.0], [1.0, 1.0]], style)
+}
+
+/// Plots the value of a sequence over time with a marker at every keyframe
+pub fn plot_sequence<T: CanTween + Float>(sequence: &AnimationSequence<T>, style: &SvgStyle) -> String {
+	let curve: Vec<[f64; 2]> = sample(sequence, style.samples)
+		.map(|(time, value)| [time, as_f64(value)])
+		.collect();
+	let markers: Vec<[f64; 2]> = sequence
+		.into_iter()
+		.map(|keyframe| [keyframe.time(), as_f64(keyframe.value)])
+		.collect();
+
+	plot(&curve, &markers, &[], style)
+}
+
+/// Plots the path of a 2D sequence with a marker at every keyframe, the Y axis points up
+pub fn plot_path<V: CanTween + Clone + Into<[T; 2]>, T: Float>(
+	sequence: &AnimationSequence<V>,
+	style: &SvgStyle,
+) -> String {
+	let point = |value: V| {
+		let [x, y] = value.into();
+		[as_f64(x), as_f64(y)]
+	};
+
+	let curve: Vec<[f64; 2]> = sample(sequence, style.samples).map(|(_, value)| point(value)).collect();
+	let markers: Vec<[f64; 2]> = sequence.into_iter().map(|keyframe| point(keyframe.value())).collect();
+
+	plot(&curve, &markers, &[], style)
+}
+
+/// Times and values of a sequence at `samples` evenly spaced times and at every keyframe
+fn sample<T: CanTween + Clone>(sequence: &AnimationSequence<T>, samples: usize) -> impl Iterator<Item = (f64, T)> + '_ {
+	let samples = samples.max(1);
+	let duration = sequence.duration();
+
+	let mut times: Vec<f64> = (0..=samples).map(|i| duration * i as f64 / samples as f64).collect();
+	times.extend(sequence.into_iter().map(|keyframe| keyframe.time()));
+	times.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+	times.dedup();
+
+	let mut sampler = sequence.clone();
+	times.into_iter().filter_map(move |time| {
+		sampler.advance_to(time);
+		sampler.now_strict().map(|value| (time, value))
+	})
+}
+
+/// Area of the image that contains the curve and the values at its edges
+struct Frame {
+	left: f64,
+	top: f64,
+	width: f64,
+	height: f64,
+	min: [f64; 2],
+	max: [f64; 2],
+}
+
+impl Frame {
+	/// Position of a point in the image
+	fn position(&self, point: [f64; 2]) -> [f64; 2] {
+		[
+			self.left + (point[0] - self.min[0]) / (self.max[0] - self.min[0]) * self.width,
+			self.top + (self.max[1] - point[1]) / (self.max[1] - self.min[1]) * self.height,
+		]
+	}
+}
+
+/// Writes the image with axes that cover the curve, the markers and the points in `include`
+fn plot(curve: &[[f64; 2]], markers: &[[f64; 2]], include: &[[f64; 2]], style: &SvgStyle) -> String {
+	let mut min = [f64::INFINITY; 2];
+	let mut max = [f64::NEG_INFINITY; 2];
+	for point in curve.iter().chain(markers).chain(include) {
+		for i in 0..2 {
+			if point[i].is_finite() {
+				min[i] = min[i].min(point[i]);
+				max[i] = max[i].max(point[i]);
+			}
+		}
+	}
+	for i in 0..2 {
+		if min[i] > max[i] {
+			// Nothing to plot
+			min[i] = 0.0;
+			max[i] = 1.0;
+		} else if min[i] == max[i] {
+			min[i] -= 0.5;
+			max[i] += 0.5;
+		}
+	}
+
+	let frame = Frame {
+		left: style.margin,
+		top: style.margin,
+		width: (style.width - style.margin * 2.0).max(1.0),
+		height: (style.height - style.margin * 2.0).max(1.0),
+		min,
+		max,
+	};
+
+	let mut svg = String::new();
+	let _ = write!(
+		svg,
+		r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+		style.width, style.height
+	);
+	svg.push('\n');
+
+	if let Some(background) = &style.background {
+		let _ = writeln!(
+			svg,
+			r#"<rect width="100%" height="100%" fill="{}"/>"#,
+			escape(background)
+		);
+	}
+
+	// Axes along the left and bottom edge of the frame
+	let bottom = frame.top + frame.height;
+	let right = frame.left + frame.width;
+	let _ = writeln!(
+		svg,
+		r#"<path d="M{:.2} {:.2}V{:.2}H{:.2}" fill="none" stroke="{}" stroke-width="1"/>"#,
+		frame.left,
+		frame.top,
+		bottom,
+		right,
+		escape(&style.axis_color)
+	);
+
+	let _ = writeln!(
+		svg,
+		r#"<g font-family="sans-serif" font-size="12" fill="{}">"#,
+		escape(&style.axis_color)
+	);
+	for (x, anchor, value) in [(frame.left, "start", min[0]), (right, "end", max[0])].iter() {
+		let _ = writeln!(
+			svg,
+			r#"<text x="{:.2}" y="{:.2}" text-anchor="{}">{}</text>"#,
+			x,
+			bottom + 16.0,
+			anchor,
+			label(*value)
+		);
+	}
+	for (y, value) in [(bottom, min[1]), (frame.top, max[1])].iter() {
+		let _ = writeln!(
+			svg,
+			r#"<text x="{:.2}" y="{:.2}" text-anchor="end" dominant-baseline="middle">{}</text>"#,
+			frame.left - 6.0,
+			y,
+			label(*value)
+		);
+	}
+	svg.push_str("</g>\n");
+
+	// Points that are not finite split the curve
+	let mut path = String::new();
+	let mut connected = false;
+	for point in curve {
+		if !(point[0].is_finite() && point[1].is_finite()) {
+			connected = false;
+			continue;
+		}
+
+		let [x, y] = frame.position(*point);
+		let _ = write!(path, "{}{:.2} {:.2}", if connected { "L" } else { "M" }, x, y);
+		connected = true;
+	}
+	if !path.is_empty() {
+		let _ = writeln!(
+			svg,
+			r#"<path d="{}" fill="none" stroke="{}" stroke-width="2" stroke-linejoin="round"/>"#,
+			path,
+			escape(&style.curve_color)
+		);
+	}
+
+	let _ = writeln!(svg, r#"<g fill="{}">"#, escape(&style.marker_color));
+	for point in markers
+		.iter()
+		.filter(|point| point[0].is_finite() && point[1].is_finite())
+	{
+		let [x, y] = frame.position(*point);
+		let _ = writeln!(svg, r#"<circle cx="{:.2}" cy="{:.2}" r="4"/>"#, x, y);
+	}
+	svg.push_str("</g>\n</svg>\n");
+
+	svg
+}
+
+/// A value with at most two decimals and without trailing zeros
+fn label(value: f64) -> String {
+	let label = format!("{:.2}", value);
+	let label = label.trim_end_matches('0').trim_end_matches('.');
+	match label {
+		"-0" => "0".to_string(),
+		label => label.to_string(),
+	}
+}
+
+/// Escapes a value for an attribute in quotes
+fn escape(value: &str) -> String {
+	value.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;")
+}
@@ -0,0 +1,505 @@
+//! A small line-based text format for animation sequences that diffs nicely and can be edited by hand.
+//!
+//! Every line is a keyframe with a time in seconds, a value and an optional easing function:
+//!
+//! ```text
+//! # time  value      function
+//! 0.0     [0.0,0.0]  ease-in-out
+//! 0.3     [0.2,0.4]  bezier-curve(0.25, 0.1, 0.25, 1)
+//! 1.0     [1.0,1.0]
+//! ```
+//!
+//! Values are numbers or lists of numbers in brackets. Functions are the names of an [`EasingRegistry`] in kebab-case
+//! (`EaseInOutCubic` is `ease-in-out-cubic`) with their parameters in parentheses, keyframes without a function use
+//! `ease-in-out` like keyframes created from a tuple. Everything after `#` is a comment and empty lines are ignored.
+//!
+//! ```rust
+//! use keyframe::{text::{parse_sequence, write_sequence}, AnimationSequence};
+//!
+//! let sequence: AnimationSequence<[f64; 2]> = parse_sequence("
+//!     0.0 [0,0] ease-in-out
+//!     0.3 [0.2,0.4] linear
+//!     1.0 [1,1]
+//! ").unwrap();
+//!
+//! assert_eq!(sequence.duration(), 1.0);
+//! assert_eq!(write_sequence(&sequence).unwrap(), "0.0 [0.0,0.0] ease-in-out\n0.3 [0.2,0.4] linear\n1.0 [1.0,1.0] ease-in-out\n");
+//!
+//! let error = parse_sequence::<f64>("0.0 1.0\n0.5 [1.0 linear").unwrap_err();
+//! assert_eq!(error.to_string(), "line 2, column 10: expected `,` or `]`");
+//! ```
+
+use alloc::{
+	string::{String, ToString},
+	vec::Vec,
+};
+use core::fmt::{self, Write};
+
+use crate::{
+	as_t, functions::EaseInOut, AnimationSequence, DynEasingFunction, EasingFunction, EasingRegistry,
+	EasingRegistryError, Float, Keyframe,
+};
+
+/// Values that can be written as a number or a list of numbers
+pub trait TextValue: Sized {
+	/// Creates a value from the numbers in the text, returns `None` if the number of components is wrong
+	fn from_components(components: &[f64]) -> Option<Self>;
+
+	/// Writes the components of the value separated by `separator`, see [`write_numbers`]
+	fn write_components(&self, out: &mut String, separator: &str);
+
+	/// Writes the value as a list of numbers like `[0.0,1.5]`, single numbers are written without brackets
+	fn write(&self, out: &mut String) {
+		out.push('[');
+		self.write_components(out, ",");
+		out.push(']');
+	}
+
+	/// Names of the components (e.g. `x` and `y`) used for columns in tables, the default is numbered columns
+	fn component_names() -> &'static [&'static str] {
+		&[]
+	}
+}
+
+/// Writes a number so that it is read back as the same value, with at least one decimal (e.g. `1.0`)
+pub fn write_number<F: Float + fmt::Debug>(out: &mut String, value: F) {
+	let _ = write!(out, "{:?}", value);
+}
+
+/// Writes numbers separated by `separator`
+pub fn write_numbers<F: Float + fmt::Debug>(out: &mut String, values: impl IntoIterator<Item = F>, separator: &str) {
+	for (i, value) in values.into_iter().enumerate() {
+		if i > 0 {
+			out.push_str(separator);
+		}
+		write_number(out, value);
+	}
+}
+
+macro_rules! impl_text_value_for_float {
+	($($float: ty),+) => {
+		$(
+			impl TextValue for $float {
+				#[inline]
+				fn from_components(components: &[f64]) -> Option<Self> {
+					match *components {
+						[value] => Some(as_t(value)),
+						_ => None,
+					}
+				}
+
+				#[inline]
+				fn write_components(&self, out: &mut String, _separator: &str) {
+					write_number(out, *self);
+				}
+
+				#[inline]
+				fn write(&self, out: &mut String) {
+					write_number(out, *self);
+				}
+			}
+		)+
+	};
+}
+
+impl_text_value_for_float!(f32, f64);
+
+impl<F: Float + fmt::Debug, const N: usize> TextValue for [F; N] {
+	fn from_components(components: &[f64]) -> Option<Self> {
+		if components.len() == N {
+			let mut array = [F::zero(); N];
+			for (value, &component) in array.iter_mut().zip(components) {
+				*value = as_t(component);
+			}
+			Some(array)
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	fn write_components(&self, out: &mut String, separator: &str) {
+		write_numbers(out, self.iter().copied(), separator);
+	}
+}
+
+impl<F: Float + fmt::Debug> TextValue for Vec<F> {
+	#[inline]
+	fn from_components(components: &[f64]) -> Option<Self> {
+		Some(components.iter().map(|&component| as_t(component)).collect())
+	}
+
+	#[inline]
+	fn write_components(&self, out: &mut String, separator: &str) {
+		write_numbers(out, self.iter().copied(), separator);
+	}
+}
+
+#[cfg(feature = "mint_types")]
+mod mint_type_impls {
+	use super::*;
+	use crate::{Point2, Point3, Vector2, Vector3, Vector4};
+
+	macro_rules! impl_text_value_for_mint {
+		($($type: ident { $($field: ident),+ }),+) => {
+			$(
+				impl<F: Float + fmt::Debug> TextValue for $type<F> {
+					fn from_components(components: &[f64]) -> Option<Self> {
+						match *components {
+							[$($field),+] => Some($type { $($field: as_t($field)),+ }),
+							_ => None,
+						}
+					}
+
+					#[inline]
+					fn write_components(&self, out: &mut String, separator: &str) {
+						write_numbers(out, [$(self.$field),+].iter().copied(), separator);
+					}
+
+					#[inline]
+					fn component_names() -> &'static [&'static str] {
+						&[$(stringify!($field)),+]
+					}
+				}
+			)+
+		};
+	}
+
+	impl_text_value_for_mint!(
+		Vector2 { x, y },
+		Vector3 { x, y, z },
+		Vector4 { x, y, z, w },
+		Point2 { x, y },
+		Point3 { x, y, z }
+	);
+}
+
+/// Category of text format error
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextErrorKind {
+	/// Something other than the expected token, e.g. "a number"
+	Expected(&'static str),
+	/// The value has the wrong number of components for the type of the sequence
+	InvalidValue(usize),
+	/// The time is negative or not finite
+	InvalidTime,
+	/// Another keyframe already exists at this time
+	TimeCollision(f64),
+	/// No function has been registered with this name
+	UnknownFunction(String),
+	/// The parameters were not accepted by the function
+	InvalidParameters(String),
+	/// The easing function is user-defined and can't be written, since it doesn't have a descriptor
+	UserDefinedFunction,
+	/// The rate that a sequence is sampled at is not positive or not finite
+	InvalidSampleRate(f64),
+}
+
+/// Error when reading or writing the text format, with the position of the error.
+/// When writing, the line is the line that the keyframe would have been written to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextError {
+	/// Line number, starting at 1, or 0 if the error isn't caused by a line (e.g. an invalid sample rate)
+	pub line: usize,
+	/// Column number in characters, starting at 1, or 0 if the error isn't caused by a line
+	pub column: usize,
+	/// What went wrong
+	pub kind: TextErrorKind,
+}
+
+impl fmt::Display for TextError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.line > 0 {
+			write!(f, "line {}, column {}: ", self.line, self.column)?;
+		}
+
+		match &self.kind {
+			TextErrorKind::Expected(expected) => write!(f, "expected {}", expected),
+			TextErrorKind::InvalidValue(components) => {
+				write!(f, "a value with {} components is not valid here", components)
+			}
+			TextErrorKind::InvalidTime => f.write_str("time has to be zero or positive"),
+			TextErrorKind::TimeCollision(time) => write!(f, "there already is a keyframe at {}", time),
+			TextErrorKind::UnknownFunction(name) => write!(f, "unknown easing function `{}`", name),
+			TextErrorKind::InvalidParameters(name) => write!(f, "invalid parameters for easing function `{}`", name),
+			TextErrorKind::UserDefinedFunction => f.write_str("user-defined easing functions can't be written"),
+			TextErrorKind::InvalidSampleRate(rate) => write!(f, "sample rate {} has to be positive", rate),
+		}
+	}
+}
+
+/// Reads a sequence with the built-in easing functions
+#[inline]
+pub fn parse_sequence<T: TextValue>(source: &str) -> Result<AnimationSequence<T>, TextError> {
+	parse_sequence_with(source, &EasingRegistry::new())
+}
+
+/// Reads a sequence with the easing functions in a registry.
+/// Function names are looked up as they are written first and in CamelCase (`ease-in-out` is `EaseInOut`) second.
+pub fn parse_sequence_with<T: TextValue>(
+	source: &str,
+	registry: &EasingRegistry,
+) -> Result<AnimationSequence<T>, TextError> {
+	let mut sequence = AnimationSequence::new();
+
+	for (i, line) in source.lines().enumerate() {
+		let line = line.split('#').next().unwrap_or_default();
+		let mut cursor = Cursor {
+			line: i + 1,
+			text: line,
+			position: 0,
+		};
+
+		cursor.skip_whitespace();
+		if cursor.is_at_end() {
+			continue;
+		}
+
+		let time_column = cursor.column();
+		let time = cursor.number()?;
+		if !time.is_finite() || time < 0.0 {
+			return Err(cursor.error_at(time_column, TextErrorKind::InvalidTime));
+		}
+
+		cursor.skip_whitespace();
+		let value_column = cursor.column();
+		let components = cursor.value()?;
+		let value = T::from_components(&components)
+			.ok_or_else(|| cursor.error_at(value_column, TextErrorKind::InvalidValue(components.len())))?;
+
+		cursor.skip_whitespace();
+		let keyframe = if cursor.is_at_end() {
+			Keyframe::new(value, time, EaseInOut)
+		} else {
+			Keyframe::new_dynamic(value, time, cursor.function(registry)?)
+		};
+
+		cursor.skip_whitespace();
+		if !cursor.is_at_end() {
+			return Err(cursor.error(TextErrorKind::Expected("the end of the line")));
+		}
+
+		sequence
+			.insert(keyframe)
+			.map_err(|_| cursor.error_at(time_column, TextErrorKind::TimeCollision(time)))?;
+	}
+
+	Ok(sequence)
+}
+
+/// Writes a sequence with one keyframe per line.
+/// Fails if a keyframe has a user-defined easing function without a [`EasingDescriptor`](crate::functions::EasingDescriptor).
+pub fn write_sequence<T: TextValue>(sequence: &AnimationSequence<T>) -> Result<String, TextError> {
+	let mut text = String::new();
+
+	for (i, keyframe) in sequence.into_iter().enumerate() {
+		let start = text.len();
+		write_number(&mut text, keyframe.time());
+		text.push(' ');
+		keyframe.value.write(&mut text);
+		text.push(' ');
+
+		let column = text[start..].chars().count() + 1;
+		if !write_function(&mut text, keyframe.function()) {
+			return Err(TextError {
+				line: i + 1,
+				column,
+				kind: TextErrorKind::UserDefinedFunction,
+			});
+		}
+		text.push('\n');
+	}
+
+	Ok(text)
+}
+
+/// Writes a function in kebab-case with its parameters, returns false if it doesn't have a descriptor
+pub(crate) fn write_function(out: &mut String, function: &dyn EasingFunction) -> bool {
+	let descriptor = match function.descriptor() {
+		Some(descriptor) => descriptor,
+		None => return false,
+	};
+
+	// The descriptor is written as `Name(parameters)`, only the name is changed
+	let function = descriptor.to_string();
+	let name = descriptor.name();
+	for (j, c) in name.char_indices() {
+		if c.is_ascii_uppercase() && j > 0 {
+			out.push('-');
+		}
+		out.push(c.to_ascii_lowercase());
+	}
+	out.push_str(&function[name.len()..]);
+	true
+}
+
+/// Reads a function written like in the text format from `text`, which starts at `column` of `line`
+pub(crate) fn parse_function(
+	text: &str,
+	line: usize,
+	column: usize,
+	registry: &EasingRegistry,
+) -> Result<DynEasingFunction, TextError> {
+	let mut cursor = Cursor {
+		line,
+		text,
+		position: 0,
+	};
+
+	cursor.skip_whitespace();
+	let function = cursor.function(registry);
+	cursor.skip_whitespace();
+
+	let result = match function {
+		Ok(_) if !cursor.is_at_end() => Err(cursor.error(TextErrorKind::Expected("the end of the function"))),
+		result => result,
+	};
+	result.map_err(|error| TextError {
+		column: error.column + column - 1,
+		..error
+	})
+}
+
+/// Position in a line that is being read
+struct Cursor<'a> {
+	line: usize,
+	text: &'a str,
+	position: usize,
+}
+
+impl<'a> Cursor<'a> {
+	#[inline]
+	fn rest(&self) -> &'a str {
+		&self.text[self.position..]
+	}
+
+	#[inline]
+	fn is_at_end(&self) -> bool {
+		self.position >= self.text.len()
+	}
+
+	#[inline]
+	fn column(&self) -> usize {
+		self.text[..self.position].chars().count() + 1
+	}
+
+	#[inline]
+	fn error_at(&self, column: usize, kind: TextErrorKind) -> TextError {
+		TextError {
+			line: self.line,
+			column,
+			kind,
+		}
+	}
+
+	#[inline]
+	fn error(&self, kind: TextErrorKind) -> TextError {
+		self.error_at(self.column(), kind)
+	}
+
+	fn skip_whitespace(&mut self) {
+		let rest = self.rest();
+		self.position += rest.len() - rest.trim_start().len();
+	}
+
+	fn eat(&mut self, c: char) -> bool {
+		self.skip_whitespace();
+		if self.rest().starts_with(c) {
+			self.position += c.len_utf8();
+			true
+		} else {
+			false
+		}
+	}
+
+	fn number(&mut self) -> Result<f64, TextError> {
+		let rest = self.rest();
+		let length = rest
+			.find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '+'))
+			.unwrap_or(rest.len());
+
+		match rest[..length].parse() {
+			Ok(number) if length > 0 => {
+				self.position += length;
+				Ok(number)
+			}
+			_ => Err(self.error(TextErrorKind::Expected("a number"))),
+		}
+	}
+
+	/// Reads a list of numbers until `end`, the opening bracket has already been read
+	fn list(&mut self, end: char, expected: &'static str) -> Result<Vec<f64>, TextError> {
+		let mut numbers = Vec::new();
+
+		self.skip_whitespace();
+		if self.eat(end) {
+			return Ok(numbers);
+		}
+
+		loop {
+			self.skip_whitespace();
+			numbers.push(self.number()?);
+
+			if self.eat(end) {
+				return Ok(numbers);
+			}
+			if !self.eat(',') {
+				return Err(self.error(TextErrorKind::Expected(expected)));
+			}
+		}
+	}
+
+	/// Reads a number or a list of numbers in brackets
+	fn value(&mut self) -> Result<Vec<f64>, TextError> {
+		if self.eat('[') {
+			self.list(']', "`,` or `]`")
+		} else {
+			self.number().map(|number| alloc::vec![number])
+		}
+	}
+
+	/// Reads a function name with optional parameters and creates it
+	fn function(&mut self, registry: &EasingRegistry) -> Result<DynEasingFunction, TextError> {
+		let column = self.column();
+		let rest = self.rest();
+		let length = rest
+			.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+			.unwrap_or(rest.len());
+		if length == 0 {
+			return Err(self.error(TextErrorKind::Expected("an easing function")));
+		}
+
+		let name = &rest[..length];
+		self.position += length;
+
+		let parameters = if self.rest().starts_with('(') {
+			self.position += 1;
+			self.list(')', "`,` or `)`")?
+		} else {
+			Vec::new()
+		};
+
+		// Names of built-in functions are written in kebab-case
+		let registered_name = if registry.contains(name) {
+			name.to_string()
+		} else {
+			name.split('-')
+				.flat_map(|word| {
+					let mut chars = word.chars();
+					chars.next().map(|c| c.to_ascii_uppercase()).into_iter().chain(chars)
+				})
+				.collect()
+		};
+
+		registry
+			.create(&registered_name, &parameters)
+			.map_err(|error| match error {
+				EasingRegistryError::UnknownFunction(_) => {
+					self.error_at(column, TextErrorKind::UnknownFunction(name.to_string()))
+				}
+				EasingRegistryError::InvalidParameters(_) => {
+					self.error_at(column, TextErrorKind::InvalidParameters(name.to_string()))
+				}
+			})
+	}
+}
@@ -0,0 +1,346 @@
+use crate::{as_f64, as_t, rotation, Float};
+
+// All matrices in this module are stored as arrays of columns.
+//
+// Transforms are interpolated the same way browsers interpolate CSS transforms:
+// they are decomposed into translation, rotation, scale, skew (and perspective), each part is interpolated separately
+// and the result is recomposed into a matrix. Interpolating the elements directly would shear and collapse rotations.
+
+/// 2D affine transform split into parts that can be interpolated independently
+struct Decomposed2D {
+	translation: [f64; 2],
+	angle: f64,
+	scale: [f64; 2],
+	skew: f64,
+}
+
+impl Decomposed2D {
+	fn new([x, y, translation]: [[f64; 2]; 3]) -> Self {
+		let scale_x = (x[0] * x[0] + x[1] * x[1]).sqrt();
+		let angle = if scale_x == 0.0 { 0.0 } else { x[1].atan2(x[0]) };
+		let (sin, cos) = angle.sin_cos();
+
+		// Rotating the Y axis back leaves an upper triangular matrix with skew and scale (negative if the transform is mirrored)
+		Decomposed2D {
+			translation,
+			angle,
+			scale: [scale_x, -sin * y[0] + cos * y[1]],
+			skew: cos * y[0] + sin * y[1],
+		}
+	}
+
+	fn ease(from: Self, to: Self, time: f64) -> Self {
+		let mut delta = (to.angle - from.angle) % (2.0 * core::f64::consts::PI);
+		if delta > core::f64::consts::PI {
+			delta -= 2.0 * core::f64::consts::PI;
+		} else if delta < -core::f64::consts::PI {
+			delta += 2.0 * core::f64::consts::PI;
+		}
+
+		Decomposed2D {
+			translation: [
+				lerp(from.translation[0], to.translation[0], time),
+				lerp(from.translation[1], to.translation[1], time),
+			],
+			angle: from.angle + delta * time,
+			scale: [
+				lerp(from.scale[0], to.scale[0], time),
+				lerp(from.scale[1], to.scale[1], time),
+			],
+			skew: lerp(from.skew, to.skew, time),
+		}
+	}
+
+	fn recompose(&self) -> [[f64; 2]; 3] {
+		let (sin, cos) = self.angle.sin_cos();
+
+		[
+			[cos * self.scale[0], sin * self.scale[0]],
+			[
+				cos * self.skew - sin * self.scale[1],
+				sin * self.skew + cos * self.scale[1],
+			],
+			self.translation,
+		]
+	}
+}
+
+/// 3D projective transform split into parts that can be interpolated independently
+struct Decomposed3D {
+	// Projective matrices are normalized so that the bottom right element is 1.0
+	w: f64,
+	perspective: [f64; 4],
+	translation: [f64; 3],
+	rotation: rotation::Quaternion,
+	// Upper triangular matrix with scale on the diagonal and skew above it
+	scale: [f64; 3],
+	skew: [f64; 3],
+}
+
+impl Decomposed3D {
+	fn new(mut m: [[f64; 4]; 4]) -> Option<Self> {
+		let w = m[3][3];
+		if w == 0.0 {
+			return None;
+		}
+		for value in m.iter_mut().flatten() {
+			*value /= w;
+		}
+
+		let translation = [m[3][0], m[3][1], m[3][2]];
+		let columns = [
+			[m[0][0], m[0][1], m[0][2]],
+			[m[1][0], m[1][1], m[1][2]],
+			[m[2][0], m[2][1], m[2][2]],
+		];
+
+		// The matrix is perspective * affine, which makes the last row (perspective)^T * affine
+		let perspective = if m[0][3] == 0.0 && m[1][3] == 0.0 && m[2][3] == 0.0 {
+			[0.0, 0.0, 0.0, 1.0]
+		} else {
+			let inverse = invert_3x3(columns)?;
+			let p = add(
+				add(scaled(inverse[0], m[0][3]), scaled(inverse[1], m[1][3])),
+				scaled(inverse[2], m[2][3]),
+			);
+			[p[0], p[1], p[2], 1.0 - dot(translation, p)]
+		};
+
+		// Gram-Schmidt orthonormalization of the columns gives rotation * (skew and scale)
+		let scale_x = length(columns[0]);
+		let x = scaled(columns[0], 1.0 / scale_x);
+
+		let skew_xy = dot(x, columns[1]);
+		let y = subtract(columns[1], scaled(x, skew_xy));
+		let scale_y = length(y);
+		let y = scaled(y, 1.0 / scale_y);
+
+		let skew_xz = dot(x, columns[2]);
+		let skew_yz = dot(y, columns[2]);
+		let z = subtract(subtract(columns[2], scaled(x, skew_xz)), scaled(y, skew_yz));
+		let mut scale_z = length(z);
+		let mut z = scaled(z, 1.0 / scale_z);
+
+		// A singular matrix has no meaningful rotation
+		if !(scale_x * scale_y * scale_z).is_normal() {
+			return None;
+		}
+
+		// Mirrored transforms are stored as a negative scale on the Z axis so the rest is a proper rotation
+		if dot(x, cross(y, z)) < 0.0 {
+			scale_z = -scale_z;
+			z = scaled(z, -1.0);
+		}
+
+		Some(Decomposed3D {
+			w,
+			perspective,
+			translation,
+			rotation: rotation::from_matrix([[x[0], y[0], z[0]], [x[1], y[1], z[1]], [x[2], y[2], z[2]]]),
+			scale: [scale_x, scale_y, scale_z],
+			skew: [skew_xy, skew_xz, skew_yz],
+		})
+	}
+
+	fn ease(from: Self, to: Self, time: f64) -> Self {
+		let mut result = from;
+
+		result.w = lerp(result.w, to.w, time);
+		for (r, t) in result.perspective.iter_mut().zip(to.perspective.iter()) {
+			*r = lerp(*r, *t, time);
+		}
+		for (r, t) in result.translation.iter_mut().zip(to.translation.iter()) {
+			*r = lerp(*r, *t, time);
+		}
+		for (r, t) in result.scale.iter_mut().zip(to.scale.iter()) {
+			*r = lerp(*r, *t, time);
+		}
+		for (r, t) in result.skew.iter_mut().zip(to.skew.iter()) {
+			*r = lerp(*r, *t, time);
+		}
+		result.rotation = rotation::slerp(result.rotation, to.rotation, time);
+
+		result
+	}
+
+	fn recompose(&self) -> [[f64; 4]; 4] {
+		let r = rotation::to_matrix(self.rotation);
+		let [x, y, z] = [
+			[r[0][0], r[1][0], r[2][0]],
+			[r[0][1], r[1][1], r[2][1]],
+			[r[0][2], r[1][2], r[2][2]],
+		];
+		let [skew_xy, skew_xz, skew_yz] = self.skew;
+
+		let columns = [
+			scaled(x, self.scale[0]),
+			add(scaled(x, skew_xy), scaled(y, self.scale[1])),
+			add(add(scaled(x, skew_xz), scaled(y, skew_yz)), scaled(z, self.scale[2])),
+			self.translation,
+		];
+
+		let mut m = [[0.0; 4]; 4];
+		for (column, values) in m.iter_mut().zip(columns.iter()) {
+			column[..3].copy_from_slice(values);
+			column[3] = dot(
+				[values[0], values[1], values[2]],
+				[self.perspective[0], self.perspective[1], self.perspective[2]],
+			);
+		}
+		m[3][3] += self.perspective[3];
+
+		for value in m.iter_mut().flatten() {
+			*value *= self.w;
+		}
+		m
+	}
+}
+
+#[inline]
+fn lerp(from: f64, to: f64, time: f64) -> f64 {
+	from + (to - from) * time
+}
+
+#[inline]
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+	a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+	[
+		a[1] * b[2] - a[2] * b[1],
+		a[2] * b[0] - a[0] * b[2],
+		a[0] * b[1] - a[1] * b[0],
+	]
+}
+
+#[inline]
+fn length(a: [f64; 3]) -> f64 {
+	dot(a, a).sqrt()
+}
+
+#[inline]
+fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+	[a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+#[inline]
+fn subtract(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+	[a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+#[inline]
+fn scaled(a: [f64; 3], factor: f64) -> [f64; 3] {
+	[a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+/// Inverts a 3x3 matrix stored as columns, returning the inverse as rows
+fn invert_3x3([a, b, c]: [[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+	// The rows of the inverse are the cross products of the columns divided by the determinant
+	let determinant = dot(a, cross(b, c));
+	if !determinant.is_normal() {
+		return None;
+	}
+
+	Some([
+		scaled(cross(b, c), 1.0 / determinant),
+		scaled(cross(c, a), 1.0 / determinant),
+		scaled(cross(a, b), 1.0 / determinant),
+	])
+}
+
+fn to_f64<V: Float, const R: usize, const C: usize>(m: [[V; R]; C]) -> [[f64; R]; C] {
+	let mut result = [[0.0; R]; C];
+	for (r, v) in result.iter_mut().flatten().zip(m.iter().flatten()) {
+		*r = as_f64(*v);
+	}
+	result
+}
+
+fn from_f64<V: Float, const R: usize, const C: usize>(m: [[f64; R]; C]) -> [[V; R]; C] {
+	let mut result = [[V::zero(); R]; C];
+	for (r, v) in result.iter_mut().flatten().zip(m.iter().flatten()) {
+		*r = as_t(*v);
+	}
+	result
+}
+
+/// Turns an array of rows into an array of columns or the other way around
+#[cfg(feature = "mint_types")]
+pub(crate) fn transpose<V: Float, const R: usize, const C: usize>(m: [[V; C]; R]) -> [[V; R]; C] {
+	let mut result = [[V::zero(); R]; C];
+	for (r, row) in m.iter().enumerate() {
+		for (c, value) in row.iter().enumerate() {
+			result[c][r] = *value;
+		}
+	}
+	result
+}
+
+/// Interpolates a 2D affine transform (X axis, Y axis, translation)
+pub(crate) fn ease_affine_2d<V: Float>(from: [[V; 2]; 3], to: [[V; 2]; 3], time: impl Float) -> [[V; 2]; 3] {
+	from_f64(
+		Decomposed2D::ease(
+			Decomposed2D::new(to_f64(from)),
+			Decomposed2D::new(to_f64(to)),
+			as_f64(time),
+		)
+		.recompose(),
+	)
+}
+
+/// Interpolates a 2D linear transform (X axis, Y axis)
+pub(crate) fn ease_linear_2d<V: Float>([fx, fy]: [[V; 2]; 2], [tx, ty]: [[V; 2]; 2], time: impl Float) -> [[V; 2]; 2] {
+	let zero = [V::zero(); 2];
+	let [x, y, _] = ease_affine_2d([fx, fy, zero], [tx, ty, zero], time);
+	[x, y]
+}
+
+/// Interpolates a 3D projective transform (X axis, Y axis, Z axis, translation).
+/// Matrices that can't be decomposed (e.g. when one of the axes is scaled to zero) are interpolated element by element.
+pub(crate) fn ease_projective_3d<V: Float>(from: [[V; 4]; 4], to: [[V; 4]; 4], time: impl Float) -> [[V; 4]; 4] {
+	let (from, to, time) = (to_f64(from), to_f64(to), as_f64(time));
+
+	from_f64(match (Decomposed3D::new(from), Decomposed3D::new(to)) {
+		(Some(f), Some(t)) => Decomposed3D::ease(f, t, time).recompose(),
+		_ => {
+			let mut result = from;
+			for (r, t) in result.iter_mut().flatten().zip(to.iter().flatten()) {
+				*r = lerp(*r, *t, time);
+			}
+			result
+		}
+	})
+}
+
+/// Interpolates a 3D affine transform (X axis, Y axis, Z axis, translation)
+pub(crate) fn ease_affine_3d<V: Float>(from: [[V; 3]; 4], to: [[V; 3]; 4], time: impl Float) -> [[V; 3]; 4] {
+	let extend = |m: [[V; 3]; 4]| {
+		let mut result = [[V::zero(); 4]; 4];
+		for (r, column) in result.iter_mut().zip(m.iter()) {
+			r[..3].copy_from_slice(column);
+		}
+		result[3][3] = V::one();
+		result
+	};
+
+	let m = ease_projective_3d(extend(from), extend(to), time);
+	[
+		[m[0][0], m[0][1], m[0][2]],
+		[m[1][0], m[1][1], m[1][2]],
+		[m[2][0], m[2][1], m[2][2]],
+		[m[3][0], m[3][1], m[3][2]],
+	]
+}
+
+/// Interpolates a 3D linear transform (X axis, Y axis, Z axis)
+pub(crate) fn ease_linear_3d<V: Float>(
+	[fx, fy, fz]: [[V; 3]; 3],
+	[tx, ty, tz]: [[V; 3]; 3],
+	time: impl Float,
+) -> [[V; 3]; 3] {
+	let zero = [V::zero(); 3];
+	let [x, y, z, _] = ease_affine_3d([fx, fy, fz, zero], [tx, ty, tz, zero], time);
+	[x, y, z]
+}